@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Meta};
+
+use crate::serializable::{attributes, lit_str};
+
+/// Name of the helper attribute used to configure the `HexFromStr` derive.
+const ATTR: &str = "hex";
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+
+    // The `Err` type, `#[hex(error = "...")]`
+    let mut error = quote!(::dusk_bytes::Error);
+
+    for meta in attributes(&input.attrs, ATTR)? {
+        match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("error") => {
+                let path: syn::Path = lit_str(&nv.lit)?.parse()?;
+                error = quote!(#path);
+            }
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "unknown `hex` attribute",
+                ));
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl ::core::str::FromStr for #ident {
+            type Err = #error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                let parsed: ::core::result::Result<Self, _> =
+                    ::dusk_bytes::__private::parse_hex_str(s);
+                parsed.map_err(::core::convert::From::from)
+            }
+        }
+    })
+}
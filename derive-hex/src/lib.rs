@@ -7,29 +7,33 @@
 use proc_macro::TokenStream;
 use quote::quote;
 
+mod from_str;
+mod serializable;
+
 #[proc_macro_derive(Hex)]
 pub fn derive_hex(item: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(item as syn::DeriveInput);
     let ident = &input.ident;
 
-    (quote! {
-        impl core::fmt::LowerHex for #ident {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                let bytes = self.to_bytes();
-
-                if f.alternate() {
-                    write!(f, "0x")?
-                }
-
-                for byte in &bytes[..] {
-                    write!(f, "{:02x}", &byte)?
-                }
+    let lower = hex_fmt(ident, quote!(core::fmt::LowerHex), "{:02x}");
+    let upper = hex_fmt(ident, quote!(core::fmt::UpperHex), "{:02X}");
 
-                Ok(())
-            }
-        }
+    (quote! {
+        #lower
+        #upper
+    })
+    .into()
+}
 
-        impl core::fmt::UpperHex for #ident {
+/// Implements the formatting trait `fmt_trait` by writing every byte of
+/// `to_bytes()` with `byte_fmt`, after a `0x` prefix in alternate mode.
+fn hex_fmt(
+    ident: &syn::Ident,
+    fmt_trait: proc_macro2::TokenStream,
+    byte_fmt: &str,
+) -> proc_macro2::TokenStream {
+    quote! {
+        impl #fmt_trait for #ident {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 let bytes = self.to_bytes();
 
@@ -38,14 +42,13 @@ pub fn derive_hex(item: TokenStream) -> TokenStream {
                 }
 
                 for byte in &bytes[..] {
-                    write!(f, "{:02X}", &byte)?
+                    write!(f, #byte_fmt, &byte)?
                 }
 
                 Ok(())
             }
         }
-    })
-    .into()
+    }
 }
 
 #[proc_macro_derive(HexDebug)]
@@ -84,3 +87,40 @@ pub fn derive_hex_debug(item: TokenStream) -> TokenStream {
     hex.extend(dbg);
     hex
 }
+
+/// Implements `core::fmt::Display` as lower case hex, prefixed by `0x` in
+/// alternate mode (`{:#}`).
+#[proc_macro_derive(HexDisplay)]
+pub fn derive_hex_display(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    hex_fmt(&input.ident, quote!(core::fmt::Display), "{:02x}").into()
+}
+
+/// Implements `core::str::FromStr` by parsing the string as hex with
+/// `dusk_bytes::ParseHexStr::from_hex_str`, rejecting any string that is not
+/// of exactly `N * 2` hex characters, after the optional `0x` prefix, with a
+/// `BadLength` error.
+///
+/// The `Err` type defaults to `dusk_bytes::Error`, and can be set with
+/// `#[hex(error = "...")]`; the parsing error is converted into it using
+/// `From`.
+#[proc_macro_derive(HexFromStr, attributes(hex))]
+pub fn derive_hex_from_str(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    from_str::expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Implements `dusk_bytes::Serializable` by concatenating the encodings of
+/// the fields in declaration order.
+#[proc_macro_derive(Serializable, attributes(bytes))]
+pub fn derive_serializable(item: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(item as syn::DeriveInput);
+
+    serializable::expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,585 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DataEnum, DeriveInput, Expr, Fields, Lit, LitInt, Meta, NestedMeta,
+};
+
+/// Name of the helper attribute used to configure the `Serializable` derive.
+const ATTR: &str = "bytes";
+
+/// Types accepted by `#[bytes(tag_type = "...")]`, with their size.
+const TAG_TYPES: [(&str, usize); 4] =
+    [("u8", 1), ("u16", 2), ("u32", 4), ("u64", 8)];
+
+pub fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`Serializable` cannot be derived for generic types",
+        ));
+    }
+
+    let container = Container::parse(&input)?;
+    let error = &container.error;
+
+    let (size, decode, encode) = match &input.data {
+        Data::Struct(data) => {
+            if container.tag_type.is_some() {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "`tag_type` can only be used on enums",
+                ));
+            }
+            expand_struct(&data.fields)?
+        }
+        Data::Enum(data) => expand_enum(data, &container)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "`Serializable` cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl ::dusk_bytes::Serializable<{ #size }> for #ident {
+            type Error = #error;
+
+            fn from_bytes(
+                buf: &[u8; Self::SIZE],
+            ) -> ::core::result::Result<Self, Self::Error> {
+                #decode
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                let mut buf = [0u8; Self::SIZE];
+                #encode
+                buf
+            }
+        }
+    })
+}
+
+/// Configuration set on the type deriving `Serializable`.
+struct Container {
+    /// The associated error type, `#[bytes(error = "...")]`
+    error: TokenStream,
+    /// The tag type used by enums, `#[bytes(tag_type = "...")]`
+    tag_type: Option<(syn::Ident, usize)>,
+}
+
+impl Container {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut error = quote!(::dusk_bytes::Error);
+        let mut tag_type = None;
+
+        for meta in attributes(&input.attrs, ATTR)? {
+            match meta {
+                Meta::NameValue(nv) if nv.path.is_ident("error") => {
+                    let path: syn::Path = lit_str(&nv.lit)?.parse()?;
+                    error = quote!(#path);
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("tag_type") => {
+                    let lit = lit_str(&nv.lit)?;
+                    let name = lit.value();
+                    let size = TAG_TYPES
+                        .iter()
+                        .find(|(ty, _)| *ty == name)
+                        .map(|(_, size)| *size)
+                        .ok_or_else(|| {
+                            syn::Error::new_spanned(
+                                lit,
+                                "expected one of `u8`, `u16`, `u32`, `u64`",
+                            )
+                        })?;
+                    tag_type = Some((syn::Ident::new(&name, lit.span()), size));
+                }
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "unknown `bytes` attribute",
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { error, tag_type })
+    }
+}
+
+fn expand_struct(
+    fields: &Fields,
+) -> syn::Result<(TokenStream, TokenStream, TokenStream)> {
+    let fields = Field::parse_all(fields)?;
+    let size = size(&fields);
+
+    let offset = if fields.iter().any(Field::is_encoded) {
+        quote!(let mut offset = 0;)
+    } else {
+        quote!()
+    };
+    let reads = reads(&fields);
+    let construct = construct(&fields, quote!(Self));
+    let decode = quote! {
+        #offset
+        #(#reads)*
+        ::core::result::Result::Ok(#construct)
+    };
+
+    let members = fields.iter().map(|field| {
+        let member = &field.member;
+        quote!(&self.#member)
+    });
+    let writes = writes(&fields, members);
+    let encode = quote! {
+        #offset
+        #(#writes)*
+    };
+
+    Ok((size, decode, encode))
+}
+
+/// Enums are encoded as a little endian tag identifying the variant, followed
+/// by the variant's fields. Smaller variants are padded with zeroes up to the
+/// size of the largest one, so that all of them have the same size.
+fn expand_enum(
+    data: &DataEnum,
+    container: &Container,
+) -> syn::Result<(TokenStream, TokenStream, TokenStream)> {
+    if data.variants.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`Serializable` cannot be derived for enums without variants",
+        ));
+    }
+
+    let error = &container.error;
+    let (tag_type, tag_size) = match &container.tag_type {
+        Some((ty, size)) => (ty.clone(), *size),
+        None => (syn::Ident::new("u8", Span::call_site()), 1),
+    };
+
+    let tags = tags(data, &tag_type, tag_size)?;
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| Field::parse_all(&variant.fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let sizes = variants.iter().map(|fields| size(fields));
+    let size = quote! {
+        #tag_size + ::dusk_bytes::__private::max(&[#(#sizes),*])
+    };
+
+    let has_fields = variants.iter().flatten().any(Field::is_encoded);
+    let offset = if has_fields {
+        quote!(let mut offset = #tag_size;)
+    } else {
+        quote!(let offset = #tag_size;)
+    };
+
+    let decode_arms = data.variants.iter().zip(&variants).zip(&tags).map(
+        |((variant, fields), tag)| {
+            let ident = &variant.ident;
+            let reads = reads(fields);
+            let construct = construct(fields, quote!(Self::#ident));
+            quote! {
+                #tag => {
+                    #(#reads)*
+                    #construct
+                }
+            }
+        },
+    );
+
+    let decode = quote! {
+        let mut tag = [0u8; #tag_size];
+        tag.copy_from_slice(&buf[..#tag_size]);
+        #offset
+
+        let value = match #tag_type::from_le_bytes(tag) {
+            #(#decode_arms)*
+            _ => return ::core::result::Result::Err(
+                <#error as ::dusk_bytes::InvalidData>::invalid_data(),
+            ),
+        };
+
+        if buf[offset..].iter().any(|b| *b != 0) {
+            return ::core::result::Result::Err(
+                <#error as ::dusk_bytes::InvalidData>::invalid_data(),
+            );
+        }
+
+        ::core::result::Result::Ok(value)
+    };
+
+    let encode_arms = data.variants.iter().zip(&variants).zip(&tags).map(
+        |((variant, fields), tag)| {
+            let ident = &variant.ident;
+            let pattern = pattern(fields, quote!(Self::#ident));
+            let writes = writes(
+                fields,
+                fields.iter().map(|field| {
+                    let var = &field.var;
+                    quote!(#var)
+                }),
+            );
+            let offset = if fields.iter().any(Field::is_encoded) {
+                quote!(let mut offset = #tag_size;)
+            } else {
+                quote!()
+            };
+            quote! {
+                #pattern => {
+                    buf[..#tag_size].copy_from_slice(
+                        &#tag.to_le_bytes(),
+                    );
+                    #offset
+                    #(#writes)*
+                }
+            }
+        },
+    );
+
+    let encode = quote! {
+        match self {
+            #(#encode_arms)*
+        }
+    };
+
+    Ok((size, decode, encode))
+}
+
+/// Returns the tag of every variant: either the value set with
+/// `#[bytes(tag = ...)]`, the explicit discriminant, or the previous tag plus
+/// one, starting from zero.
+fn tags(
+    data: &DataEnum,
+    tag_type: &syn::Ident,
+    tag_size: usize,
+) -> syn::Result<Vec<LitInt>> {
+    let max = u64::MAX >> (64 - tag_size * 8);
+
+    let mut tags = Vec::with_capacity(data.variants.len());
+    let mut seen = HashSet::new();
+    let mut next = Some(0u64);
+
+    for variant in &data.variants {
+        let mut tag = None;
+
+        for meta in attributes(&variant.attrs, ATTR)? {
+            match meta {
+                Meta::NameValue(nv) if nv.path.is_ident("tag") => {
+                    tag = Some(lit_int(&nv.lit)?);
+                }
+                meta => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "unknown `bytes` attribute",
+                    ));
+                }
+            }
+        }
+
+        if tag.is_none() {
+            if let Some((_, discriminant)) = &variant.discriminant {
+                match discriminant {
+                    Expr::Lit(syn::ExprLit { lit, .. }) => {
+                        tag = Some(lit_int(lit)?);
+                    }
+                    expr => {
+                        return Err(syn::Error::new_spanned(
+                            expr,
+                            "cannot use a non literal discriminant as tag, \
+                             set it with `#[bytes(tag = ...)]`",
+                        ));
+                    }
+                }
+            }
+        }
+
+        let (value, span) = match tag {
+            Some(lit) => (lit.base10_parse::<u64>()?, lit.span()),
+            None => match next {
+                Some(value) => (value, variant.ident.span()),
+                None => {
+                    return Err(syn::Error::new_spanned(
+                        &variant.ident,
+                        "the tag overflows, set it with `#[bytes(tag = ...)]`",
+                    ));
+                }
+            },
+        };
+
+        if value > max {
+            return Err(syn::Error::new(
+                span,
+                format!("the tag {value} doesn't fit in {tag_size} byte(s)"),
+            ));
+        }
+        if !seen.insert(value) {
+            return Err(syn::Error::new(
+                span,
+                format!("the tag {value} is used more than once"),
+            ));
+        }
+
+        tags.push(LitInt::new(&format!("{value}{tag_type}"), span));
+        next = value.checked_add(1);
+    }
+
+    Ok(tags)
+}
+
+/// Collects the items of every `#[name(...)]` attribute.
+pub(crate) fn attributes(
+    attrs: &[syn::Attribute],
+    name: &str,
+) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => metas.push(meta),
+                        NestedMeta::Lit(lit) => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected an attribute, found a literal",
+                            ));
+                        }
+                    }
+                }
+            }
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    format!("expected `#[{name}(...)]`"),
+                ));
+            }
+        }
+    }
+
+    Ok(metas)
+}
+
+pub(crate) fn lit_str(lit: &Lit) -> syn::Result<&syn::LitStr> {
+    match lit {
+        Lit::Str(s) => Ok(s),
+        lit => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+    }
+}
+
+fn lit_int(lit: &Lit) -> syn::Result<LitInt> {
+    match lit {
+        Lit::Int(i) => Ok(i.clone()),
+        lit => Err(syn::Error::new_spanned(lit, "expected an integer literal")),
+    }
+}
+
+/// A field of a struct or of an enum variant.
+struct Field {
+    /// The field's name or index
+    member: syn::Member,
+    /// The variable holding the field while decoding and encoding
+    var: syn::Ident,
+    ty: syn::Type,
+    codec: Codec,
+}
+
+/// How a field is encoded, set with `#[bytes(...)]` on the field.
+enum Codec {
+    /// Using the field's [`Serializable`] implementation
+    Serializable,
+    /// Not encoded, and set using [`Default`] when decoding,
+    /// `#[bytes(skip)]`
+    Skip,
+    /// Using the `to_bytes` and `from_bytes` functions of the given module,
+    /// `#[bytes(with = "...")]`
+    With(syn::Path),
+    /// As a big endian integer, `#[bytes(be)]`
+    BigEndian,
+}
+
+impl Field {
+    fn parse_all(fields: &Fields) -> syn::Result<Vec<Self>> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let member = match &field.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(syn::Index::from(i)),
+                };
+
+                let mut codec = Codec::Serializable;
+                for meta in attributes(&field.attrs, ATTR)? {
+                    if !matches!(codec, Codec::Serializable) {
+                        return Err(syn::Error::new_spanned(
+                            meta,
+                            "only one of `skip`, `with` and `be` can be used",
+                        ));
+                    }
+                    codec = match meta {
+                        Meta::Path(path) if path.is_ident("skip") => {
+                            Codec::Skip
+                        }
+                        Meta::Path(path) if path.is_ident("be") => {
+                            Codec::BigEndian
+                        }
+                        Meta::NameValue(nv) if nv.path.is_ident("with") => {
+                            Codec::With(lit_str(&nv.lit)?.parse()?)
+                        }
+                        meta => {
+                            return Err(syn::Error::new_spanned(
+                                meta,
+                                "unknown `bytes` attribute",
+                            ));
+                        }
+                    };
+                }
+
+                Ok(Self {
+                    member,
+                    var: format_ident!("__field{}", i),
+                    ty: field.ty.clone(),
+                    codec,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether the field takes up any bytes in the encoding.
+    fn is_encoded(&self) -> bool {
+        !matches!(self.codec, Codec::Skip)
+    }
+}
+
+/// The encoded size of the given fields, as a constant expression.
+fn size(fields: &[Field]) -> TokenStream {
+    let sizes = fields.iter().filter(|f| f.is_encoded()).map(|field| {
+        let ty = &field.ty;
+        match &field.codec {
+            Codec::Serializable => quote! {
+                ::dusk_bytes::__private::size_of(
+                    ::core::marker::PhantomData::<#ty>
+                )
+            },
+            Codec::With(path) => quote! {
+                ::dusk_bytes::__private::size_of_with(
+                    ::core::marker::PhantomData::<#ty>,
+                    #path::to_bytes,
+                )
+            },
+            Codec::BigEndian => quote!(::core::mem::size_of::<#ty>()),
+            Codec::Skip => unreachable!("skipped fields have no size"),
+        }
+    });
+    let sizes: Vec<_> = sizes.collect();
+
+    if sizes.is_empty() {
+        quote!(0)
+    } else {
+        quote!(#(#sizes)+*)
+    }
+}
+
+/// Reads the fields from `buf` at `offset`, in declaration order, into their
+/// variables.
+fn reads(fields: &[Field]) -> impl Iterator<Item = TokenStream> + '_ {
+    fields.iter().map(|field| {
+        let var = &field.var;
+        let ty = &field.ty;
+        let value = match &field.codec {
+            Codec::Serializable => quote! {
+                ::dusk_bytes::__private::read(&buf[..], &mut offset)?
+            },
+            Codec::Skip => quote!(::core::default::Default::default()),
+            Codec::With(path) => quote! {
+                #path::from_bytes(
+                    &::dusk_bytes::__private::take(&buf[..], &mut offset),
+                )?
+            },
+            Codec::BigEndian => quote! {
+                <#ty>::from_be_bytes(
+                    ::dusk_bytes::__private::take(&buf[..], &mut offset),
+                )
+            },
+        };
+        quote!(let #var: #ty = #value;)
+    })
+}
+
+/// Writes the given field references into `buf` at `offset`, in declaration
+/// order.
+fn writes<'a>(
+    fields: &'a [Field],
+    members: impl Iterator<Item = TokenStream> + 'a,
+) -> impl Iterator<Item = TokenStream> + 'a {
+    fields.iter().zip(members).filter_map(|(field, member)| {
+        let write = match &field.codec {
+            Codec::Serializable => quote! {
+                ::dusk_bytes::__private::write(
+                    #member,
+                    &mut buf[..],
+                    &mut offset,
+                );
+            },
+            Codec::Skip => return None,
+            Codec::With(path) => quote! {
+                ::dusk_bytes::__private::put(
+                    &#path::to_bytes(#member),
+                    &mut buf[..],
+                    &mut offset,
+                );
+            },
+            Codec::BigEndian => {
+                let ty = &field.ty;
+                quote! {
+                    ::dusk_bytes::__private::put(
+                        &<#ty>::to_be_bytes(*#member),
+                        &mut buf[..],
+                        &mut offset,
+                    );
+                }
+            }
+        };
+        Some(write)
+    })
+}
+
+/// Builds `path` out of the fields' variables.
+fn construct(fields: &[Field], path: TokenStream) -> TokenStream {
+    let members = fields.iter().map(|field| &field.member);
+    let vars = fields.iter().map(|field| &field.var);
+
+    quote!(#path { #(#members: #vars),* })
+}
+
+/// A pattern matching `path` and binding the encoded fields to their
+/// variables.
+fn pattern(fields: &[Field], path: TokenStream) -> TokenStream {
+    let members = fields.iter().map(|field| &field.member);
+    let vars = fields.iter().map(|field| {
+        if field.is_encoded() {
+            let var = &field.var;
+            quote!(#var)
+        } else {
+            quote!(_)
+        }
+    });
+
+    quote!(#path { #(#members: #vars),* })
+}
@@ -4,16 +4,17 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use derive_hex::HexDebug;
+use derive_hex::{HexDebug, HexDisplay};
 
-#[derive(HexDebug)]
+#[derive(HexDebug, HexDisplay)]
 struct Beef {}
 
 // Cannot import as dev-dependencies `dusk-bytes` since it creates a circular
 // dependendecies that `cargo publish` can't understand is legit, until
 // `cargo publish --all` is implemented (or similar solution).
 //
-// So we manually add the `to_bytes` method requested by `HexDebug` macro.
+// So we manually add the `to_bytes` method requested by `HexDebug` and
+// `HexDisplay` macros.
 impl Beef {
     pub fn to_bytes(&self) -> [u8; 2] {
         [0xbe, 0xef]
@@ -50,6 +51,21 @@ mod display {
 
         assert_eq!(format!("{:#X}", beef), "0xBEEF");
     }
+
+    #[test]
+    fn display() {
+        let beef = Beef {};
+
+        assert_eq!(format!("{}", beef), "beef");
+        assert_eq!(beef.to_string(), "beef");
+    }
+
+    #[test]
+    fn display_alt() {
+        let beef = Beef {};
+
+        assert_eq!(format!("{:#}", beef), "0xbeef");
+    }
 }
 
 mod debug {
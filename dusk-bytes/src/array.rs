@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! [`Serializable`] implementations for arrays, encoding the elements one
+//! after the other.
+//!
+//! Arrays of elements of one byte (e.g. `[u8; M]`) are implemented for any
+//! length, while the other arrays are implemented for elements of 2, 4, 8,
+//! 16, 32, 48, 64 and 96 bytes, and up to 32 elements. Any other array is
+//! serialized through the [`ArrayN`] wrapper, named by the
+//! [`array!`](crate::array) macro.
+
+use crate::Serializable;
+
+/// Deserialize the `M` elements of `E` bytes each out of `buf`, in order.
+/// The error of the first element that fails to deserialize is returned.
+fn from_bytes<T, const E: usize, const M: usize>(
+    buf: &[u8],
+) -> Result<[T; M], T::Error>
+where
+    T: Serializable<E>,
+{
+    let mut error = None;
+
+    let elements: [Option<T>; M] = core::array::from_fn(|i| {
+        if error.is_some() {
+            return None;
+        }
+
+        let mut bytes = [0u8; E];
+        bytes.copy_from_slice(&buf[i * E..(i + 1) * E]);
+
+        match T::from_bytes(&bytes) {
+            Ok(element) => Some(element),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }
+    });
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(elements
+            .map(|element| element.expect("every element is deserialized"))),
+    }
+}
+
+/// Serialize every element of `elements` in order, into `buf`.
+fn to_bytes<T, const E: usize>(elements: &[T], buf: &mut [u8])
+where
+    T: Serializable<E>,
+{
+    for (chunk, element) in buf.chunks_exact_mut(E).zip(elements) {
+        chunk.copy_from_slice(&element.to_bytes());
+    }
+}
+
+/// Arrays of any length of elements of one byte, such as `[u8; M]`.
+impl<T, const M: usize> Serializable<M> for [T; M]
+where
+    T: Serializable<1>,
+{
+    type Error = T::Error;
+
+    fn from_bytes(buf: &[u8; M]) -> Result<Self, Self::Error> {
+        from_bytes::<T, 1, M>(buf)
+    }
+
+    fn to_bytes(&self) -> [u8; M] {
+        let mut buf = [0u8; M];
+        to_bytes(self, &mut buf);
+        buf
+    }
+}
+
+macro_rules! impl_serializable_array {
+    ($size:literal; $($len:literal)*) => {
+        $(
+            #[doc = concat!(
+                "Arrays of ", $len, " elements of ", $size, " bytes. Arrays ",
+                "of elements of 2, 4, 8, 16, 32, 48, 64 and 96 bytes are ",
+                "implemented for up to 32 elements, any other array is ",
+                "serialized through [`ArrayN`]."
+            )]
+            impl<T> Serializable<{ $size * $len }> for [T; $len]
+            where
+                T: Serializable<$size>,
+            {
+                type Error = T::Error;
+
+                fn from_bytes(
+                    buf: &[u8; $size * $len],
+                ) -> Result<Self, Self::Error> {
+                    from_bytes::<T, $size, $len>(buf)
+                }
+
+                fn to_bytes(&self) -> [u8; $size * $len] {
+                    let mut buf = [0u8; $size * $len];
+                    to_bytes(self, &mut buf);
+                    buf
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_serializable_arrays {
+    ($($size:literal)*) => {
+        $(
+            impl_serializable_array!($size;
+                1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+                17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+            );
+        )*
+    };
+}
+
+impl_serializable_arrays!(2 4 8 16 32 48 64 96);
+
+/// An array of `M` elements of `E` bytes each, implementing [`Serializable`]
+/// for any element size and length.
+///
+/// The elements are encoded one after the other, and `N` must be `E * M`,
+/// which is checked at compile time. The [`array!`](crate::array) macro fills
+/// the sizes in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArrayN<T, const E: usize, const M: usize, const N: usize>(
+    pub [T; M],
+);
+
+impl<T, const E: usize, const M: usize, const N: usize> ArrayN<T, E, M, N> {
+    /// Returns the wrapped array.
+    pub fn into_inner(self) -> [T; M] {
+        self.0
+    }
+}
+
+impl<T, const E: usize, const M: usize, const N: usize> From<[T; M]>
+    for ArrayN<T, E, M, N>
+{
+    fn from(array: [T; M]) -> Self {
+        Self(array)
+    }
+}
+
+impl<T, const E: usize, const M: usize, const N: usize> Serializable<N>
+    for ArrayN<T, E, M, N>
+where
+    T: Serializable<E>,
+{
+    type Error = T::Error;
+
+    fn from_bytes(buf: &[u8; N]) -> Result<Self, Self::Error> {
+        const {
+            assert!(
+                N == E * M,
+                "the array's size must be the sum of its elements' sizes"
+            )
+        };
+
+        from_bytes::<T, E, M>(buf).map(Self)
+    }
+
+    fn to_bytes(&self) -> [u8; N] {
+        const {
+            assert!(
+                N == E * M,
+                "the array's size must be the sum of its elements' sizes"
+            )
+        };
+
+        let mut buf = [0u8; N];
+        to_bytes(&self.0, &mut buf);
+        buf
+    }
+}
+
+/// Names the [`ArrayN`] wrapper for `M` elements of the given type,
+/// computing the sizes of the elements and of the array at compile time.
+///
+/// ```rust
+/// use dusk_bytes::Serializable;
+///
+/// // 40 compressed points of 33 bytes
+/// type Points = dusk_bytes::array!([u8; 33]; 40);
+///
+/// let points = Points::from([[2; 33]; 40]);
+/// assert_eq!(Points::SIZE, 1320);
+///
+/// let bytes = points.to_bytes();
+/// assert_eq!(Points::from_bytes(&bytes).unwrap(), points);
+/// ```
+#[macro_export]
+macro_rules! array {
+    ($ty:ty; $len:expr) => {
+        $crate::ArrayN<
+            $ty,
+            { $crate::__private::size_of(::core::marker::PhantomData::<$ty>) },
+            { $len },
+            {
+                $crate::__private::size_of(::core::marker::PhantomData::<$ty>)
+                    * $len
+            },
+        >
+    };
+}
@@ -0,0 +1,211 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use super::errors::{BadLength, InvalidChar};
+use super::serialize::Serializable;
+
+const RFC4648: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The base32 variants: the ones of RFC 4648, and Crockford's.
+///
+/// All of them are encoded in upper case, and parsed case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base32 {
+    /// The RFC 4648 alphabet (`A` to `Z` and `2` to `7`), padded with `=`.
+    Rfc4648,
+    /// The RFC 4648 alphabet (`A` to `Z` and `2` to `7`), without padding.
+    Rfc4648NoPad,
+    /// The Crockford alphabet (`0` to `9` and `A` to `Z`, without `I`, `L`,
+    /// `O` and `U`), without padding. When parsing, `I` and `L` are read as
+    /// `1`, `O` as `0`, and hyphens are ignored wherever they are.
+    Crockford,
+}
+
+impl Base32 {
+    /// Returns the number of characters `len` bytes are encoded into.
+    pub const fn encoded_len(self, len: usize) -> usize {
+        if self.padded() {
+            len.div_ceil(5) * 8
+        } else {
+            (len * 8).div_ceil(5)
+        }
+    }
+
+    const fn padded(self) -> bool {
+        matches!(self, Self::Rfc4648)
+    }
+
+    /// Returns whether the character `c` is ignored when parsing.
+    const fn ignored(self, c: u8) -> bool {
+        matches!(self, Self::Crockford) && c == b'-'
+    }
+
+    const fn alphabet(self) -> &'static [u8; 32] {
+        match self {
+            Self::Rfc4648 | Self::Rfc4648NoPad => RFC4648,
+            Self::Crockford => CROCKFORD,
+        }
+    }
+
+    /// Returns the value of the character `c`, in any case, if it belongs to
+    /// the alphabet.
+    fn decode(self, c: u8) -> Option<u32> {
+        let c = match (self, c.to_ascii_uppercase()) {
+            (Self::Crockford, b'O') => b'0',
+            (Self::Crockford, b'I' | b'L') => b'1',
+            (_, c) => c,
+        };
+
+        self.alphabet()
+            .iter()
+            .position(|&a| a == c)
+            .map(|value| value as u32)
+    }
+}
+
+/// An optional trait used to parse a base32 string slice for types that
+/// implements the [`Serializable`] trait.
+/// The default implementation makes use of [`Serializable`] trait to provide
+/// the necessary parsing functionality without additional code from the
+/// consumer.
+pub trait ParseBase32Str<const N: usize>: Serializable<N> {
+    /// Parse a string slice as the base32 representation of the `N` bytes of
+    /// [`Self`], in the given variant, case-insensitively.
+    ///
+    /// - If the string isn't exactly as long as the encoding of `N` bytes,
+    ///   without counting the hyphens ignored by [`Base32::Crockford`], such
+    ///   as when the padding is missing, it returns a [`BadLength`] error.
+    /// - If a character out of the alphabet is found, if the padding is
+    ///   misplaced, or if the unused bits of the last character are not zero,
+    ///   it returns an [`InvalidChar`] error.
+    fn from_base32_str(s: &str, variant: Base32) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar,
+    {
+        let s = s.as_bytes();
+
+        let expected = variant.encoded_len(N);
+        let found = s.iter().filter(|&&c| !variant.ignored(c)).count();
+        if found != expected {
+            return Err(Self::Error::bad_length(found, expected));
+        }
+
+        let digits = Base32::Rfc4648NoPad.encoded_len(N);
+
+        let mut bytes = [0u8; N];
+        let mut acc = 0u32;
+        let mut bits = 0;
+        let mut j = 0;
+
+        // The number of characters read, and the position of the last digit
+        let mut read = 0;
+        let mut last = 0;
+
+        for (i, &c) in s.iter().enumerate() {
+            if variant.ignored(c) {
+                continue;
+            }
+            read += 1;
+
+            if read > digits {
+                if c != b'=' {
+                    return Err(Self::Error::invalid_char(c.into(), i));
+                }
+                continue;
+            }
+
+            let value = variant
+                .decode(c)
+                .ok_or_else(|| Self::Error::invalid_char(c.into(), i))?;
+            last = i;
+
+            acc = (acc << 5) | value;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes[j] = (acc >> bits) as u8;
+                acc &= (1 << bits) - 1;
+                j += 1;
+            }
+        }
+
+        // The bits of the last character not part of any byte must be zero,
+        // so that the encoding is canonical
+        if acc != 0 {
+            return Err(Self::Error::invalid_char(s[last].into(), last));
+        }
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// An optional trait used to encode types that implements the
+/// [`Serializable`] trait as base32, the counterpart of [`ParseBase32Str`].
+///
+/// The output buffer must be big enough for the padded encoding of `N`
+/// bytes, `M >= 8 * ceil(N / 5)`, which is checked at compile time.
+///
+/// ```rust
+/// use dusk_bytes::{Base32, ParseBase32Str, ToBase32};
+///
+/// let mut buf = [0u8; 8];
+/// let s = 0xfbff_u16.to_base32(&mut buf, Base32::Rfc4648);
+/// assert_eq!(s, "775Q====");
+///
+/// let s = 0xfbff_u16.to_base32(&mut buf, Base32::Crockford);
+/// assert_eq!(s, "ZZXG");
+///
+/// assert_eq!(u16::from_base32_str("zzxg", Base32::Crockford), Ok(0xfbff));
+/// ```
+pub trait ToBase32<const N: usize>: Serializable<N> {
+    /// Encode [`Self`] as base32 in the given variant into `out`, returning
+    /// the encoded string slice.
+    fn to_base32<'a, const M: usize>(
+        &self,
+        out: &'a mut [u8; M],
+        variant: Base32,
+    ) -> &'a str {
+        const {
+            assert!(
+                M >= N.div_ceil(5) * 8,
+                "the output buffer is too small for the base32 encoding"
+            )
+        };
+
+        let alphabet = variant.alphabet();
+        let mut acc = 0u32;
+        let mut bits = 0;
+        let mut len = 0;
+
+        for byte in self.to_bytes() {
+            acc = (acc << 8) | byte as u32;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out[len] = alphabet[(acc >> bits) as usize & 0x1f];
+                len += 1;
+            }
+        }
+        if bits > 0 {
+            out[len] = alphabet[(acc << (5 - bits)) as usize & 0x1f];
+            len += 1;
+        }
+
+        let encoded_len = variant.encoded_len(N);
+        out[len..encoded_len].fill(b'=');
+
+        core::str::from_utf8(&out[..encoded_len])
+            .expect("base32 is encoded as ASCII")
+    }
+}
+
+// Auto trait [`ParseBase32Str`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ParseBase32Str<N> for T where T: Serializable<N> {}
+
+// Auto trait [`ToBase32`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ToBase32<N> for T where T: Serializable<N> {}
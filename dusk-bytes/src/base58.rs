@@ -0,0 +1,241 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use super::errors::{BadLength, InvalidChar, InvalidData};
+use super::serialize::Serializable;
+use super::sha256::sha256;
+
+/// The Bitcoin base58 alphabet, without `0`, `O`, `I` and `l`.
+const ALPHABET: &[u8; 58] =
+    b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The number of bytes of the base58check checksum.
+const CHECKSUM_LEN: usize = 4;
+
+/// Returns the maximum number of characters `len` bytes are encoded into.
+///
+/// Every byte takes `log(256) / log(58) ~ 1.37` characters.
+const fn max_encoded_len(len: usize) -> usize {
+    len * 138 / 100 + 1
+}
+
+/// Returns the value of the character `c`, if it belongs to the alphabet.
+fn decode_char(c: u8) -> Option<u32> {
+    ALPHABET
+        .iter()
+        .position(|&a| a == c)
+        .map(|value| value as u32)
+}
+
+/// Returns the first bytes of the double SHA-256 of `bytes`.
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let hash = sha256(&sha256(bytes));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// An optional trait used to parse a base58 string slice, in the Bitcoin
+/// alphabet, for types that implements the [`Serializable`] trait.
+/// The default implementation makes use of [`Serializable`] trait to provide
+/// the necessary parsing functionality without additional code from the
+/// consumer.
+pub trait ParseBase58Str<const N: usize>: Serializable<N> {
+    /// Parse a string slice as the base58 representation of the `N` bytes of
+    /// [`Self`].
+    ///
+    /// - If a character out of the alphabet is found, it returns an
+    ///   [`InvalidChar`] error.
+    /// - If the string doesn't decode to exactly `N` bytes, it returns a
+    ///   [`BadLength`] error. Since the decoding stops as soon as the value
+    ///   doesn't fit in `N` bytes, the length found is then `N + 1`.
+    fn from_base58_str(s: &str) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar,
+    {
+        let mut bytes = [0u8; N];
+        decode(s.as_bytes(), &mut bytes, &mut [])?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a string slice as the base58check representation of the `N`
+    /// bytes of [`Self`], followed by the first 4 bytes of their double
+    /// SHA-256 as checksum.
+    ///
+    /// The errors are the ones of [`ParseBase58Str::from_base58_str`], with
+    /// the lengths counting the checksum, and an [`InvalidData`] error if the
+    /// checksum doesn't match.
+    fn from_base58check_str(s: &str) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar + InvalidData,
+    {
+        let mut bytes = [0u8; N];
+        let mut check = [0u8; CHECKSUM_LEN];
+        decode(s.as_bytes(), &mut bytes, &mut check)?;
+
+        if checksum(&bytes) != check {
+            return Err(Self::Error::invalid_data());
+        }
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// An optional trait used to encode types that implements the
+/// [`Serializable`] trait as base58, in the Bitcoin alphabet, the counterpart
+/// of [`ParseBase58Str`].
+///
+/// The output buffer must be big enough for the longest encoding of `N`
+/// bytes, [`ToBase58::BASE58_LEN`] or [`ToBase58::BASE58CHECK_LEN`], which
+/// is checked at compile time.
+///
+/// ```rust
+/// use dusk_bytes::{ParseBase58Str, ToBase58};
+///
+/// let mut buf = [0u8; u32::BASE58_LEN];
+/// let s = 0x0000_ffff_u32.to_base58(&mut buf);
+/// assert_eq!(s, "7YXVfM");
+/// assert_eq!(u32::from_base58_str(s), Ok(0x0000_ffff));
+///
+/// let mut buf = [0u8; u32::BASE58CHECK_LEN];
+/// let s = 0x0000_ffff_u32.to_base58check(&mut buf);
+/// assert_eq!(s, "jpV15smzBkM");
+/// assert_eq!(u32::from_base58check_str(s), Ok(0x0000_ffff));
+/// ```
+pub trait ToBase58<const N: usize>: Serializable<N> {
+    /// The maximum number of characters of the base58 encoding of `N` bytes.
+    const BASE58_LEN: usize = max_encoded_len(N);
+
+    /// The maximum number of characters of the base58check encoding of `N`
+    /// bytes.
+    const BASE58CHECK_LEN: usize = max_encoded_len(N + CHECKSUM_LEN);
+
+    /// Encode [`Self`] as base58 into `out`, returning the encoded string
+    /// slice.
+    fn to_base58<'a, const M: usize>(&self, out: &'a mut [u8; M]) -> &'a str {
+        const {
+            assert!(
+                M >= max_encoded_len(N),
+                "the output buffer is too small for the base58 encoding"
+            )
+        };
+
+        encode(&self.to_bytes(), &[], out)
+    }
+
+    /// Encode [`Self`] as base58check into `out`, appending the first 4 bytes
+    /// of the double SHA-256 of the bytes as checksum, and returning the
+    /// encoded string slice.
+    fn to_base58check<'a, const M: usize>(
+        &self,
+        out: &'a mut [u8; M],
+    ) -> &'a str {
+        const {
+            assert!(
+                M >= max_encoded_len(N + CHECKSUM_LEN),
+                "the output buffer is too small for the base58check encoding"
+            )
+        };
+
+        let bytes = self.to_bytes();
+        encode(&bytes, &checksum(&bytes), out)
+    }
+}
+
+/// Encode the big-endian number made of `bytes` followed by `check` into
+/// `out`, returning the encoded string slice.
+fn encode<'a>(bytes: &[u8], check: &[u8], out: &'a mut [u8]) -> &'a str {
+    // The base58 digits, least significant first
+    let mut len = 0;
+
+    for &byte in bytes.iter().chain(check) {
+        let mut carry = byte as u32;
+        for digit in out[..len].iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            out[len] = (carry % 58) as u8;
+            len += 1;
+            carry /= 58;
+        }
+    }
+
+    // Every leading zero byte is encoded as a leading `1`
+    let zeros = bytes.iter().chain(check).take_while(|&&b| b == 0).count();
+    out[len..len + zeros].fill(0);
+    len += zeros;
+
+    let out = &mut out[..len];
+    out.reverse();
+    for digit in out.iter_mut() {
+        *digit = ALPHABET[*digit as usize];
+    }
+
+    core::str::from_utf8(out).expect("base58 is encoded as ASCII")
+}
+
+/// Decode `s` into the big-endian number made of `bytes` followed by `check`,
+/// failing unless it is exactly as long as both.
+fn decode<E>(s: &[u8], bytes: &mut [u8], check: &mut [u8]) -> Result<(), E>
+where
+    E: BadLength + InvalidChar,
+{
+    let expected = bytes.len() + check.len();
+
+    // The decoded bytes, least significant first
+    let mut len = 0;
+
+    for (i, &c) in s.iter().enumerate() {
+        let mut carry =
+            decode_char(c).ok_or_else(|| E::invalid_char(c.into(), i))?;
+
+        for k in 0..len {
+            let byte = byte_at(bytes, check, k);
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if len == expected {
+                return Err(E::bad_length(expected + 1, expected));
+            }
+            *byte_at(bytes, check, len) = carry as u8;
+            len += 1;
+            carry >>= 8;
+        }
+    }
+
+    // Every leading `1` is decoded as a leading zero byte, the bytes are
+    // already zeroed
+    let found = len + s.iter().take_while(|&&c| c == b'1').count();
+    if found != expected {
+        return Err(E::bad_length(found, expected));
+    }
+
+    Ok(())
+}
+
+/// Returns the `k`-th least significant byte of the big-endian number made
+/// of `bytes` followed by `check`.
+fn byte_at<'a>(
+    bytes: &'a mut [u8],
+    check: &'a mut [u8],
+    k: usize,
+) -> &'a mut u8 {
+    match check.len().checked_sub(k + 1) {
+        Some(i) => &mut check[i],
+        None => &mut bytes[bytes.len() + check.len() - k - 1],
+    }
+}
+
+// Auto trait [`ParseBase58Str`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ParseBase58Str<N> for T where T: Serializable<N> {}
+
+// Auto trait [`ToBase58`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ToBase58<N> for T where T: Serializable<N> {}
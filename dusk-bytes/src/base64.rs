@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use super::errors::{BadLength, InvalidChar};
+use super::serialize::Serializable;
+
+const STANDARD: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The base64 variants of RFC 4648.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base64 {
+    /// The standard alphabet (`+` and `/`), padded with `=`.
+    Standard,
+    /// The standard alphabet (`+` and `/`), without padding.
+    StandardNoPad,
+    /// The URL and filename safe alphabet (`-` and `_`), padded with `=`.
+    UrlSafe,
+    /// The URL and filename safe alphabet (`-` and `_`), without padding.
+    UrlSafeNoPad,
+}
+
+impl Base64 {
+    /// Returns the number of characters `len` bytes are encoded into.
+    pub const fn encoded_len(self, len: usize) -> usize {
+        if self.padded() {
+            len.div_ceil(3) * 4
+        } else {
+            (len * 4).div_ceil(3)
+        }
+    }
+
+    const fn padded(self) -> bool {
+        matches!(self, Self::Standard | Self::UrlSafe)
+    }
+
+    const fn alphabet(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard | Self::StandardNoPad => STANDARD,
+            Self::UrlSafe | Self::UrlSafeNoPad => URL_SAFE,
+        }
+    }
+
+    /// Returns the value of the character `c`, if it belongs to the alphabet.
+    fn decode(self, c: u8) -> Option<u32> {
+        self.alphabet()
+            .iter()
+            .position(|&a| a == c)
+            .map(|value| value as u32)
+    }
+}
+
+/// An optional trait used to parse a base64 string slice for types that
+/// implements the [`Serializable`] trait.
+/// The default implementation makes use of [`Serializable`] trait to provide
+/// the necessary parsing functionality without additional code from the
+/// consumer.
+pub trait ParseBase64Str<const N: usize>: Serializable<N> {
+    /// Parse a string slice as the base64 representation of the `N` bytes of
+    /// [`Self`], in the given variant.
+    ///
+    /// - If the string isn't exactly as long as the encoding of `N` bytes,
+    ///   such as when the padding is missing, it returns a [`BadLength`]
+    ///   error.
+    /// - If a character out of the alphabet is found, if the padding is
+    ///   misplaced, or if the unused bits of the last character are not zero,
+    ///   it returns an [`InvalidChar`] error.
+    fn from_base64_str(s: &str, variant: Base64) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar,
+    {
+        let expected = variant.encoded_len(N);
+        if s.len() != expected {
+            return Err(Self::Error::bad_length(s.len(), expected));
+        }
+
+        let s = s.as_bytes();
+        let digits = Base64::StandardNoPad.encoded_len(N);
+
+        let mut bytes = [0u8; N];
+        let mut acc = 0u32;
+        let mut bits = 0;
+        let mut j = 0;
+
+        for (i, &c) in s.iter().enumerate() {
+            if i >= digits {
+                if c != b'=' {
+                    return Err(Self::Error::invalid_char(c.into(), i));
+                }
+                continue;
+            }
+
+            let value = variant
+                .decode(c)
+                .ok_or_else(|| Self::Error::invalid_char(c.into(), i))?;
+
+            acc = (acc << 6) | value;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                bytes[j] = (acc >> bits) as u8;
+                acc &= (1 << bits) - 1;
+                j += 1;
+            }
+        }
+
+        // The bits of the last character not part of any byte must be zero,
+        // so that the encoding is canonical
+        if acc != 0 {
+            let i = digits - 1;
+            return Err(Self::Error::invalid_char(s[i].into(), i));
+        }
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// An optional trait used to encode types that implements the
+/// [`Serializable`] trait as base64, the counterpart of [`ParseBase64Str`].
+///
+/// The output buffer must be big enough for the padded encoding of `N`
+/// bytes, `M >= 4 * ceil(N / 3)`, which is checked at compile time.
+///
+/// ```rust
+/// use dusk_bytes::{Base64, ParseBase64Str, ToBase64};
+///
+/// let mut buf = [0u8; 8];
+/// let s = 0xfbff_u16.to_base64(&mut buf, Base64::Standard);
+/// assert_eq!(s, "//s=");
+///
+/// let s = 0xfbff_u16.to_base64(&mut buf, Base64::UrlSafeNoPad);
+/// assert_eq!(s, "__s");
+///
+/// assert_eq!(u16::from_base64_str("__s", Base64::UrlSafeNoPad), Ok(0xfbff));
+/// ```
+pub trait ToBase64<const N: usize>: Serializable<N> {
+    /// Encode [`Self`] as base64 in the given variant into `out`, returning
+    /// the encoded string slice.
+    fn to_base64<'a, const M: usize>(
+        &self,
+        out: &'a mut [u8; M],
+        variant: Base64,
+    ) -> &'a str {
+        const {
+            assert!(
+                M >= N.div_ceil(3) * 4,
+                "the output buffer is too small for the base64 encoding"
+            )
+        };
+
+        let alphabet = variant.alphabet();
+        let bytes = self.to_bytes();
+        let mut len = 0;
+
+        for chunk in bytes.chunks(3) {
+            let n = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+
+            for i in 0..=chunk.len() {
+                out[len] = alphabet[(n >> (18 - 6 * i)) as usize & 0x3f];
+                len += 1;
+            }
+        }
+
+        let encoded_len = variant.encoded_len(N);
+        out[len..encoded_len].fill(b'=');
+
+        core::str::from_utf8(&out[..encoded_len])
+            .expect("base64 is encoded as ASCII")
+    }
+}
+
+// Auto trait [`ParseBase64Str`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ParseBase64Str<N> for T where T: Serializable<N> {}
+
+// Auto trait [`ToBase64`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ToBase64<N> for T where T: Serializable<N> {}
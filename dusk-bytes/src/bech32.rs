@@ -0,0 +1,358 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use super::errors::{BadLength, InvalidChar, InvalidData};
+use super::serialize::Serializable;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+const GENERATOR: [u32; 5] =
+    [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// The number of characters of the checksum.
+const CHECKSUM_LEN: usize = 6;
+
+/// The separator between the human-readable part and the data.
+const SEPARATOR: u8 = b'1';
+
+/// The maximum number of characters of the human-readable part.
+const MAX_HRP_LEN: usize = 83;
+
+/// The checksum variants of the bech32 encoding.
+///
+/// Unlike BIP-173, the encoding isn't limited to 90 characters, so that
+/// values of any size can be encoded: the checksum is only guaranteed to
+/// detect up to 4 errors in strings of up to 90 characters, and bigger values
+/// are better hashed or split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bech32 {
+    /// The original bech32 checksum, as specified by BIP-173.
+    Original,
+    /// The bech32m checksum, as specified by BIP-350, to be preferred for new
+    /// formats.
+    Modified,
+}
+
+impl Bech32 {
+    /// Returns the number of characters `len` bytes are encoded into, with
+    /// the human-readable part `hrp`.
+    pub const fn encoded_len(hrp: &str, len: usize) -> usize {
+        hrp.len() + 1 + data_len(len) + CHECKSUM_LEN
+    }
+
+    /// The value a valid checksum leaves the polymod with.
+    const fn constant(self) -> u32 {
+        match self {
+            Self::Original => 1,
+            Self::Modified => 0x2bc830a3,
+        }
+    }
+}
+
+/// Returns the number of 5 bits characters `len` bytes are encoded into.
+const fn data_len(len: usize) -> usize {
+    (len * 8).div_ceil(5)
+}
+
+/// Returns the value of the lower case character `c`, if it belongs to the
+/// charset.
+fn decode_char(c: u8) -> Option<u8> {
+    CHARSET
+        .iter()
+        .position(|&a| a == c)
+        .map(|value| value as u8)
+}
+
+/// Check that the human-readable part `hrp` is of 1 to 83 characters in the
+/// ASCII range `33..=126`.
+fn check_hrp<E>(hrp: &[u8]) -> Result<(), E>
+where
+    E: BadLength + InvalidChar,
+{
+    if hrp.is_empty() {
+        return Err(E::bad_length(0, 1));
+    }
+    if hrp.len() > MAX_HRP_LEN {
+        return Err(E::bad_length(hrp.len(), MAX_HRP_LEN));
+    }
+    if let Some(i) = hrp.iter().position(|c| !(33..=126).contains(c)) {
+        return Err(E::invalid_char(hrp[i].into(), i));
+    }
+    Ok(())
+}
+
+/// Feed the 5 bits value `v` to the BCH checksum `chk`.
+fn polymod_step(chk: u32, v: u8) -> u32 {
+    let top = chk >> 25;
+    let mut chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+    for (i, g) in GENERATOR.iter().enumerate() {
+        if (top >> i) & 1 == 1 {
+            chk ^= g;
+        }
+    }
+    chk
+}
+
+/// Returns the checksum of the expansion of the human-readable part `hrp`,
+/// in lower case.
+fn polymod_hrp(hrp: &[u8]) -> u32 {
+    let hrp = hrp.iter().map(u8::to_ascii_lowercase);
+
+    let mut chk = 1;
+    for c in hrp.clone() {
+        chk = polymod_step(chk, c >> 5);
+    }
+    chk = polymod_step(chk, 0);
+    for c in hrp {
+        chk = polymod_step(chk, c & 0x1f);
+    }
+    chk
+}
+
+/// Returns the position, among the `len` data and checksum characters, of
+/// the single substituted character that would turn the `residue` of an
+/// invalid checksum into zero, if any.
+///
+/// The checksum is linear: a character changed by `e` at position `p`
+/// changes the checksum by `e` fed through `len - 1 - p` zeroes, whatever
+/// the other characters are.
+fn locate_error(residue: u32, len: usize) -> Option<usize> {
+    // The change of every bit of a character at the current position
+    let mut bits = [1, 2, 4, 8, 16];
+
+    for p in (0..len).rev() {
+        for e in 1..32 {
+            let change = bits
+                .iter()
+                .enumerate()
+                .filter(|(b, _)| (e >> b) & 1 == 1)
+                .fold(0, |change, (_, bit)| change ^ bit);
+            if change == residue {
+                return Some(p);
+            }
+        }
+        for bit in bits.iter_mut() {
+            *bit = polymod_step(*bit, 0);
+        }
+    }
+    None
+}
+
+/// An optional trait used to parse a bech32 string slice for types that
+/// implements the [`Serializable`] trait.
+/// The default implementation makes use of [`Serializable`] trait to provide
+/// the necessary parsing functionality without additional code from the
+/// consumer.
+pub trait ParseBech32Str<const N: usize>: Serializable<N> {
+    /// Parse a string slice as the bech32 representation of the `N` bytes of
+    /// [`Self`], with the human-readable part `hrp` and the checksum of the
+    /// given variant.
+    ///
+    /// The string slice may be all upper case or all lower case, and the
+    /// human-readable part is compared case-insensitively. The string isn't
+    /// limited to 90 characters, see [`Bech32`].
+    ///
+    /// - If `hrp` is empty or longer than 83 characters, it returns a
+    ///   [`BadLength`] error, and if it contains a character out of the ASCII
+    ///   range `33..=126`, an [`InvalidChar`] error at its position in `hrp`.
+    /// - If the case is mixed, if the human-readable part or the separator
+    ///   don't match, or if a character out of the charset is found, it
+    ///   returns an [`InvalidChar`] error.
+    /// - If the string isn't exactly as long as the encoding of `N` bytes, it
+    ///   returns a [`BadLength`] error.
+    /// - If the checksum doesn't match, and a single substituted character
+    ///   explains it, it returns an [`InvalidChar`] error at its position.
+    ///   Otherwise, it returns an [`InvalidData`] error.
+    /// - If the unused bits of the last data character are not zero, it
+    ///   returns an [`InvalidChar`] error.
+    fn from_bech32_str(
+        s: &str,
+        hrp: &str,
+        variant: Bech32,
+    ) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar + InvalidData,
+    {
+        let s = s.as_bytes();
+        let hrp = hrp.as_bytes();
+        check_hrp(hrp)?;
+
+        // The case of the first cased character, every other one must match
+        if let Some(upper) = s.iter().find(|c| c.is_ascii_alphabetic()) {
+            let upper = upper.is_ascii_uppercase();
+            if let Some(i) = s.iter().position(|c| {
+                c.is_ascii_alphabetic() && c.is_ascii_uppercase() != upper
+            }) {
+                return Err(Self::Error::invalid_char(s[i].into(), i));
+            }
+        }
+
+        let prefix = hrp.iter().chain(&[SEPARATOR]);
+        for (i, (&c, &expected)) in s.iter().zip(prefix).enumerate() {
+            if !c.eq_ignore_ascii_case(&expected) {
+                return Err(Self::Error::invalid_char(c.into(), i));
+            }
+        }
+
+        let expected = hrp.len() + 1 + data_len(N) + CHECKSUM_LEN;
+        if s.len() != expected {
+            return Err(Self::Error::bad_length(s.len(), expected));
+        }
+
+        let offset = hrp.len() + 1;
+        let data = &s[offset..];
+
+        let mut chk = polymod_hrp(hrp);
+        let mut bytes = [0u8; N];
+        let mut acc = 0u32;
+        let mut bits = 0;
+        let mut j = 0;
+
+        for (i, &c) in data.iter().enumerate() {
+            let value =
+                decode_char(c.to_ascii_lowercase()).ok_or_else(|| {
+                    Self::Error::invalid_char(c.into(), offset + i)
+                })?;
+            chk = polymod_step(chk, value);
+
+            if i >= data_len(N) {
+                continue;
+            }
+            acc = (acc << 5) | value as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes[j] = (acc >> bits) as u8;
+                acc &= (1 << bits) - 1;
+                j += 1;
+            }
+        }
+
+        let residue = chk ^ variant.constant();
+        if residue != 0 {
+            return Err(match locate_error(residue, data.len()) {
+                Some(p) => {
+                    Self::Error::invalid_char(data[p].into(), offset + p)
+                }
+                None => Self::Error::invalid_data(),
+            });
+        }
+
+        // The bits of the last data character not part of any byte must be
+        // zero, so that the encoding is canonical
+        if acc != 0 {
+            let i = data_len(N) - 1;
+            return Err(Self::Error::invalid_char(data[i].into(), offset + i));
+        }
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// An optional trait used to encode types that implements the
+/// [`Serializable`] trait as bech32, the counterpart of [`ParseBech32Str`].
+///
+/// The output buffer must be big enough for the human-readable part, the
+/// separator, the data and the checksum, as given by
+/// [`Bech32::encoded_len`]. Since the human-readable part is only known at
+/// runtime, the output buffer is checked at compile time to be big enough
+/// for the shortest one only.
+///
+/// ```rust
+/// use dusk_bytes::{Bech32, ParseBech32Str, ToBech32};
+///
+/// let key = [0xde, 0xad, 0xbe, 0xef];
+///
+/// let mut buf = [0u8; Bech32::encoded_len("dusk", 4)];
+/// let s = key.to_bech32("dusk", &mut buf, Bech32::Modified)?;
+/// assert_eq!(s, "dusk1m6kmamcrrs4tj");
+///
+/// let parsed = <[u8; 4]>::from_bech32_str(s, "dusk", Bech32::Modified)?;
+/// assert_eq!(parsed, key);
+/// # Ok::<(), dusk_bytes::Error>(())
+/// ```
+pub trait ToBech32<const N: usize>: Serializable<N> {
+    /// Encode [`Self`] as bech32 into `out`, with the human-readable part
+    /// `hrp` and the checksum of the given variant, returning the encoded
+    /// string slice.
+    ///
+    /// The human-readable part is written in lower case. The encoding isn't
+    /// limited to 90 characters, see [`Bech32`].
+    ///
+    /// - If the human-readable part is empty or longer than 83 characters,
+    ///   or if `out` is too small for it, it returns a [`BadLength`] error.
+    /// - If the human-readable part contains a character out of the ASCII
+    ///   range `33..=126`, it returns an [`InvalidChar`] error.
+    fn to_bech32<'a, const M: usize>(
+        &self,
+        hrp: &str,
+        out: &'a mut [u8; M],
+        variant: Bech32,
+    ) -> Result<&'a str, Self::Error>
+    where
+        Self::Error: BadLength + InvalidChar,
+    {
+        const {
+            assert!(
+                M >= data_len(N) + CHECKSUM_LEN + 2,
+                "the output buffer is too small for the bech32 encoding"
+            )
+        };
+
+        let hrp = hrp.as_bytes();
+        check_hrp(hrp)?;
+
+        let encoded_len = hrp.len() + 1 + data_len(N) + CHECKSUM_LEN;
+        if M < encoded_len {
+            return Err(Self::Error::bad_length(M, encoded_len));
+        }
+
+        for (o, c) in out.iter_mut().zip(hrp) {
+            *o = c.to_ascii_lowercase();
+        }
+        out[hrp.len()] = SEPARATOR;
+
+        let mut chk = polymod_hrp(hrp);
+        let mut len = hrp.len() + 1;
+        let mut emit = |value: u8| {
+            chk = polymod_step(chk, value);
+            out[len] = CHARSET[value as usize];
+            len += 1;
+        };
+
+        let mut acc = 0u32;
+        let mut bits = 0;
+        for byte in self.to_bytes() {
+            acc = (acc << 8) | byte as u32;
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                emit((acc >> bits) as u8 & 0x1f);
+            }
+        }
+        if bits > 0 {
+            emit((acc << (5 - bits)) as u8 & 0x1f);
+        }
+
+        for _ in 0..CHECKSUM_LEN {
+            chk = polymod_step(chk, 0);
+        }
+        let chk = chk ^ variant.constant();
+        for i in 0..CHECKSUM_LEN {
+            out[len + i] = CHARSET[(chk >> (5 * (5 - i))) as usize & 0x1f];
+        }
+
+        Ok(core::str::from_utf8(&out[..encoded_len])
+            .expect("bech32 is encoded as ASCII"))
+    }
+}
+
+// Auto trait [`ParseBech32Str`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ParseBech32Str<N> for T where T: Serializable<N> {}
+
+// Auto trait [`ToBech32`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ToBech32<N> for T where T: Serializable<N> {}
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Branch-free conversions between nibbles and hex characters, for the
+//! constant-time paths of [`ParseHexStr`](crate::ParseHexStr) and
+//! [`ToHex`](crate::ToHex).
+//!
+//! The comparisons are computed on `i16`, where `(a - b) >> 8` is `-1` (all
+//! bits set) when `a < b` and `0` otherwise, and used as masks instead of
+//! branching or indexing a table with the secret value.
+
+/// Returns the value of the hex character `c`, or `0xffff` if `c` is not an
+/// hex character.
+pub(crate) fn decode_nibble(c: u8) -> u16 {
+    let c = c as i16;
+    let mut n: i16 = -1;
+
+    // '0'..='9', 0x30..=0x39
+    n += (((0x2f - c) & (c - 0x3a)) >> 8) & (c - 0x2f);
+    // 'A'..='F', 0x41..=0x46
+    n += (((0x40 - c) & (c - 0x47)) >> 8) & (c - 0x36);
+    // 'a'..='f', 0x61..=0x66
+    n += (((0x60 - c) & (c - 0x67)) >> 8) & (c - 0x56);
+
+    n as u16
+}
+
+/// Returns the hex character of the nibble `n`, in upper case if `upper`.
+pub(crate) fn encode_nibble(n: u8, upper: bool) -> u8 {
+    let n = n as i16;
+    // The offset from `'0' + n` to the letters, for `n > 9`
+    let letters: i16 = if upper { 0x41 - 0x3a } else { 0x61 - 0x3a };
+
+    (n + 0x30 + (((9 - n) >> 8) & letters)) as u8
+}
+
+/// Returns `a` if `choice` is `0xffff` and `b` if it is `0`.
+pub(crate) fn select(choice: u16, a: usize, b: usize) -> usize {
+    let mask = (choice as i16 as isize) as usize;
+    (a & mask) | (b & !mask)
+}
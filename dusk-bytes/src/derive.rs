@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Support functions for the code generated by `#[derive(Serializable)]`.
+//! They are not part of the public API.
+
+use core::marker::PhantomData;
+
+use super::serialize::Serializable;
+
+/// Returns the size `N` of a type implementing [`Serializable<N>`], letting
+/// the compiler infer `N` from the type alone.
+pub const fn size_of<T, const N: usize>(_: PhantomData<T>) -> usize
+where
+    T: Serializable<N>,
+{
+    N
+}
+
+/// Returns the size `N` of the bytes returned by the `to_bytes` function of a
+/// `#[bytes(with = "...")]` module.
+pub const fn size_of_with<T, const N: usize>(
+    _: PhantomData<T>,
+    _: fn(&T) -> [u8; N],
+) -> usize {
+    N
+}
+
+/// Returns the biggest of the given sizes, or zero if there are none.
+pub const fn max(sizes: &[usize]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < sizes.len() {
+        if sizes[i] > max {
+            max = sizes[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Copy `N` bytes of `buf` starting at `offset`, and advance `offset` past
+/// them.
+pub fn take<const N: usize>(buf: &[u8], offset: &mut usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&buf[*offset..*offset + N]);
+    *offset += N;
+
+    bytes
+}
+
+/// Copy `bytes` into `buf` starting at `offset`, and advance `offset` past
+/// them.
+pub fn put<const N: usize>(
+    bytes: &[u8; N],
+    buf: &mut [u8],
+    offset: &mut usize,
+) {
+    buf[*offset..*offset + N].copy_from_slice(bytes);
+    *offset += N;
+}
+
+/// Deserialize a `T` from the bytes of `buf` starting at `offset`, and
+/// advance `offset` past them.
+pub fn read<T, const N: usize>(
+    buf: &[u8],
+    offset: &mut usize,
+) -> Result<T, T::Error>
+where
+    T: Serializable<N>,
+{
+    T::from_bytes(&take(buf, offset))
+}
+
+/// Serialize `value` into `buf` starting at `offset`, and advance `offset`
+/// past the bytes written.
+pub fn write<T, const N: usize>(value: &T, buf: &mut [u8], offset: &mut usize)
+where
+    T: Serializable<N>,
+{
+    put(&value.to_bytes(), buf, offset);
+}
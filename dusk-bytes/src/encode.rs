@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use super::ct;
+use super::serialize::Serializable;
+
+const LOWER: &[u8; 16] = b"0123456789abcdef";
+const UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+/// An optional trait used to encode types that implements the
+/// [`Serializable`] trait as ASCII hex, the counterpart of
+/// [`ParseHexStr`](crate::ParseHexStr).
+/// The default implementation makes use of [`Serializable`] trait to provide
+/// the necessary encoding functionality without additional code from the
+/// consumer, and without going through `core::fmt`.
+///
+/// The output buffer must be of `M = N * 2` bytes, which is checked at
+/// compile time.
+///
+/// ```rust
+/// use dusk_bytes::ToHex;
+///
+/// let mut hex = [0u8; 4];
+/// 0xbeefu16.to_hex_bytes(&mut hex);
+/// assert_eq!(&hex, b"efbe");
+///
+/// 0xbeefu16.to_upper_hex_bytes(&mut hex);
+/// assert_eq!(&hex, b"EFBE");
+/// ```
+pub trait ToHex<const N: usize>: Serializable<N> {
+    /// Encode [`Self`] as lower case hex characters into `out`.
+    fn to_hex_bytes<const M: usize>(&self, out: &mut [u8; M]) {
+        encode(&self.to_bytes(), out, LOWER);
+    }
+
+    /// Encode [`Self`] as upper case hex characters into `out`.
+    fn to_upper_hex_bytes<const M: usize>(&self, out: &mut [u8; M]) {
+        encode(&self.to_bytes(), out, UPPER);
+    }
+
+    /// Encode [`Self`] as lower case hex characters into `out`, in constant
+    /// time.
+    ///
+    /// Meant for secret material, such as private keys: the characters are
+    /// computed without branching on the bytes, nor using them to index a
+    /// table.
+    fn to_hex_bytes_ct<const M: usize>(&self, out: &mut [u8; M]) {
+        encode_ct(&self.to_bytes(), out, false);
+    }
+
+    /// Encode [`Self`] as upper case hex characters into `out`, in constant
+    /// time, as [`ToHex::to_hex_bytes_ct`] does.
+    fn to_upper_hex_bytes_ct<const M: usize>(&self, out: &mut [u8; M]) {
+        encode_ct(&self.to_bytes(), out, true);
+    }
+}
+
+/// Encode every byte of `bytes` as two characters of `digits` into `out`.
+fn encode<const N: usize, const M: usize>(
+    bytes: &[u8; N],
+    out: &mut [u8; M],
+    digits: &[u8; 16],
+) {
+    const {
+        assert!(
+            M == N * 2,
+            "the hex output must be twice the size of the bytes encoded"
+        )
+    };
+
+    for (byte, chars) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        chars[0] = digits[(byte >> 4) as usize];
+        chars[1] = digits[(byte & 0x0f) as usize];
+    }
+}
+
+/// Encode every byte of `bytes` as two hex characters into `out`, without
+/// branching on the bytes.
+fn encode_ct<const N: usize, const M: usize>(
+    bytes: &[u8; N],
+    out: &mut [u8; M],
+    upper: bool,
+) {
+    const {
+        assert!(
+            M == N * 2,
+            "the hex output must be twice the size of the bytes encoded"
+        )
+    };
+
+    for (byte, chars) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        chars[0] = ct::encode_nibble(byte >> 4, upper);
+        chars[1] = ct::encode_nibble(byte & 0x0f, upper);
+    }
+}
+
+// Auto trait [`ToHex`] for any type that implements [`Serializable`]
+impl<T, const N: usize> ToHex<N> for T where T: Serializable<N> {}
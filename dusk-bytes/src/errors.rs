@@ -10,6 +10,17 @@
 pub trait BadLength {
     /// Invoked when a buffer of bad length is given to [`from_slice`]
     fn bad_length(found: usize, expected: usize) -> Self;
+
+    /// Invoked when a reader or a writer fails to transfer the `expected`
+    /// bytes for another reason than their length, such as an I/O error.
+    ///
+    /// Defaults to a bad length of `0` bytes.
+    fn io_error(expected: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::bad_length(0, expected)
+    }
 }
 
 /// Trait to be implemented for the associated Error used in
@@ -22,6 +33,42 @@ pub trait InvalidChar {
     fn invalid_char(ch: char, index: usize) -> Self;
 }
 
+/// Trait to be implemented for the associated Error used in
+/// [`from_slice_exact`] and [`from_hex_str_exact`].
+/// The function is called if the input given is longer than the mandatory
+/// size for the struct.
+///
+/// [`from_slice_exact`]: crate::DeserializableSlice::from_slice_exact
+/// [`from_hex_str_exact`]: crate::ParseHexStr::from_hex_str_exact
+pub trait TrailingBytes {
+    /// Invoked when an input longer than `expected` is given to the exact
+    /// functions
+    fn trailing_bytes(found: usize, expected: usize) -> Self;
+}
+
+/// Trait to be implemented for the associated Error used in
+/// [`from_hex_str_exact`].
+/// The function is called if the string slice has an odd number of
+/// characters, that can't be parsed as bytes.
+///
+/// [`from_hex_str_exact`]: crate::ParseHexStr::from_hex_str_exact
+pub trait OddLength {
+    /// Invoked when a string slice with an odd length is given to
+    /// [`from_hex_str_exact`](crate::ParseHexStr::from_hex_str_exact)
+    fn odd_length(found: usize) -> Self;
+}
+
+/// Trait to be implemented for the associated Error of types whose
+/// [`Serializable::from_bytes`](crate::Serializable::from_bytes) rejects bytes
+/// that don't represent a valid value, such as the types deriving
+/// [`Serializable`](crate::Serializable) from an enum when an unknown tag is
+/// found.
+pub trait InvalidData {
+    /// Invoked when the bytes given are not a valid representation of the
+    /// type
+    fn invalid_data() -> Self;
+}
+
 /// Dusk Bytes operation error variants
 #[derive(Copy, Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Error {
@@ -46,12 +93,70 @@ pub enum Error {
         /// The character's index
         index: usize,
     },
+    /// Returned from the default implementation of [`from_slice_exact`] and
+    /// [`from_hex_str_exact`] if the input given is longer than the mandatory
+    /// size for the struct.
+    ///
+    /// [`from_slice_exact`]: crate::DeserializableSlice::from_slice_exact
+    /// [`from_hex_str_exact`]: crate::ParseHexStr::from_hex_str_exact
+    TrailingBytes {
+        /// The input's length
+        found: usize,
+        /// The expected input's length
+        expected: usize,
+    },
+    /// Returned from the default implementation of [`from_hex_str_exact`] if
+    /// the string slice given has an odd number of characters.
+    ///
+    /// [`from_hex_str_exact`]: crate::ParseHexStr::from_hex_str_exact
+    OddLength {
+        /// The string slice's length
+        found: usize,
+    },
+    /// Returned when a decoded value doesn't fit in the type it's decoded
+    /// into, e.g. a [`U64Size`](crate::U64Size) too big for the platform's
+    /// `usize`.
+    Overflow,
+    /// Returned by the [`Read`](crate::Read) and [`Write`](crate::Write)
+    /// adapters of the `std` feature when the underlying I/O operation fails.
+    /// The `std::io::Error` is kept by the adapter.
+    Io,
+    /// Returned when a decoded length prefix is bigger than the maximum length
+    /// accepted by the caller.
+    LengthLimit {
+        /// The length prefix decoded
+        found: usize,
+        /// The maximum length accepted
+        max: usize,
+    },
 }
 
 impl BadLength for Error {
     fn bad_length(found: usize, expected: usize) -> Self {
         Self::BadLength { found, expected }
     }
+
+    fn io_error(_expected: usize) -> Self {
+        Self::Io
+    }
+}
+
+impl TrailingBytes for Error {
+    fn trailing_bytes(found: usize, expected: usize) -> Self {
+        Self::TrailingBytes { found, expected }
+    }
+}
+
+impl OddLength for Error {
+    fn odd_length(found: usize) -> Self {
+        Self::OddLength { found }
+    }
+}
+
+impl InvalidData for Error {
+    fn invalid_data() -> Self {
+        Self::InvalidData
+    }
 }
 
 impl InvalidChar for Error {
@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Adapters implementing the crate's [`Read`] and [`Write`] on top of
+//! [`std::io::Read`] and [`std::io::Write`].
+//!
+//! The crate's traits report their errors as an [`Error`], which can't carry
+//! a [`std::io::Error`]: a failed I/O operation is reported as [`Error::Io`],
+//! and the [`std::io::Error`] itself is kept by the adapter, to be retrieved
+//! with `take_error`. The functions reading and writing [`Serializable`] types
+//! with a custom error report it through [`BadLength::io_error`].
+//!
+//! [`Serializable`]: crate::Serializable
+
+use std::io;
+
+use crate::{BadLength, Error, Read, Write};
+
+/// Adapter implementing [`Read`] for any [`std::io::Read`], such as a `File`,
+/// a `TcpStream` or a `BufReader`.
+///
+/// Running out of bytes fails with [`Error::BadLength`], reporting how many
+/// bytes were read, while any other I/O error fails with [`Error::Io`].
+///
+/// ```rust
+/// use dusk_bytes::{DeserializableSlice, IoReader};
+///
+/// let file = std::io::Cursor::new([0x04, 0x03, 0x02, 0x01]);
+/// let mut r = IoReader::new(file);
+///
+/// assert_eq!(u32::from_reader(&mut r), Ok(0x01020304));
+/// ```
+#[derive(Debug)]
+pub struct IoReader<R> {
+    inner: R,
+    error: Option<io::Error>,
+}
+
+/// Adapter implementing [`Write`] for any [`std::io::Write`], such as a
+/// `File`, a `TcpStream` or a `BufWriter`.
+///
+/// Any I/O error fails with [`Error::Io`].
+///
+/// ```rust
+/// use dusk_bytes::{DynSerializable, IoWriter};
+///
+/// let mut w = IoWriter::new(Vec::new());
+/// 0x01020304u32.write_to(&mut w).unwrap();
+///
+/// assert_eq!(w.into_inner(), [0x04, 0x03, 0x02, 0x01]);
+/// ```
+#[derive(Debug)]
+pub struct IoWriter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+macro_rules! impl_adapter {
+    ($name:ident, $ty:ident) => {
+        impl<$ty> $name<$ty> {
+            /// Wraps the given I/O object.
+            pub fn new(inner: $ty) -> Self {
+                Self { inner, error: None }
+            }
+
+            /// Returns a reference to the wrapped I/O object.
+            pub fn get_ref(&self) -> &$ty {
+                &self.inner
+            }
+
+            /// Returns a mutable reference to the wrapped I/O object.
+            pub fn get_mut(&mut self) -> &mut $ty {
+                &mut self.inner
+            }
+
+            /// Returns the wrapped I/O object.
+            pub fn into_inner(self) -> $ty {
+                self.inner
+            }
+
+            /// Returns the I/O error behind the last [`Error::Io`] returned,
+            /// if any, leaving [`None`] in its place.
+            pub fn take_error(&mut self) -> Option<io::Error> {
+                self.error.take()
+            }
+        }
+    };
+}
+
+impl_adapter!(IoReader, R);
+impl_adapter!(IoWriter, W);
+
+impl<R> Read for IoReader<R>
+where
+    R: io::Read,
+{
+    /// The number of bytes left in a stream isn't known, so it's always
+    /// reported as `0`.
+    fn capacity(&self) -> usize {
+        0
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut read = 0;
+
+        while read < buf.len() {
+            match self.inner.read(&mut buf[read..]) {
+                Ok(0) => return Err(Error::bad_length(read, buf.len())),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.error = Some(err);
+                    return Err(Error::Io);
+                }
+            }
+        }
+
+        Ok(read)
+    }
+}
+
+impl<W> Write for IoWriter<W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        match self.inner.write_all(buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(err) => {
+                self.error = Some(err);
+                Err(Error::Io)
+            }
+        }
+    }
+}
@@ -8,12 +8,62 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array;
+mod base32;
+mod base58;
+mod base64;
+mod bech32;
+mod ct;
+mod derive;
+mod encode;
 mod errors;
+#[cfg(feature = "std")]
+mod io;
+mod option;
 mod parse;
+#[cfg(feature = "alloc")]
+mod prefixed;
 mod primitive;
 mod serialize;
+mod sha256;
+mod tuple;
+mod varint;
 
-pub use derive_hex::{Hex, HexDebug};
-pub use errors::{BadLength, Error, InvalidChar};
+pub use array::ArrayN;
+pub use base32::{Base32, ParseBase32Str, ToBase32};
+pub use base58::{ParseBase58Str, ToBase58};
+pub use base64::{Base64, ParseBase64Str, ToBase64};
+pub use bech32::{Bech32, ParseBech32Str, ToBech32};
+pub use derive_hex::{Hex, HexDebug, HexDisplay, HexFromStr, Serializable};
+pub use encode::ToHex;
+pub use errors::{
+    BadLength, Error, InvalidChar, InvalidData, OddLength, TrailingBytes,
+};
+#[cfg(feature = "std")]
+pub use io::{IoReader, IoWriter};
+pub use option::OptionN;
 pub use parse::{ParseHexStr, hex};
-pub use serialize::{DeserializableSlice, Read, Serializable, Write};
+#[cfg(feature = "alloc")]
+pub use prefixed::{LengthPrefix, LengthPrefixed};
+pub use primitive::{Be, CanonicalNan, I32Size, I64Size, Le, U32Size, U64Size};
+pub use serialize::{
+    DeserializableSlice, DynSerializable, Fixed, Read, Serializable,
+    SerializableSlice, Write,
+};
+pub use tuple::{
+    Tuple2, Tuple3, Tuple4, Tuple5, Tuple6, Tuple7, Tuple8, Tuple9, Tuple10,
+    Tuple11, Tuple12,
+};
+pub use varint::Varint;
+
+// Not public API, used by the code generated by the macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::derive::*;
+    pub use crate::parse::{hex_literal_len, parse_hex_literal, parse_hex_str};
+}
@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! [`Serializable`] implementations for [`Option`], encoded as a presence
+//! byte (`0` for `None`, `1` for `Some`) followed by the value, or by zeroes
+//! when absent.
+//!
+//! Options are implemented for values of up to 128 bytes, and of 192 and 256
+//! bytes. Any other option is serialized through the [`OptionN`] wrapper,
+//! named by the [`option!`](crate::option) macro.
+
+use crate::{InvalidData, Serializable};
+
+/// Deserialize an [`Option`] out of its presence byte and the bytes of the
+/// value, rejecting presence bytes other than `0` and `1`, and non-zero
+/// padding when the value is absent.
+fn from_bytes<T, const N: usize>(
+    presence: u8,
+    buf: &[u8],
+) -> Result<Option<T>, T::Error>
+where
+    T: Serializable<N>,
+    T::Error: InvalidData,
+{
+    match presence {
+        0 if buf.iter().all(|b| *b == 0) => Ok(None),
+        1 => {
+            let mut bytes = [0u8; N];
+            bytes.copy_from_slice(buf);
+            T::from_bytes(&bytes).map(Some)
+        }
+        _ => Err(T::Error::invalid_data()),
+    }
+}
+
+/// Serialize an [`Option`] into `buf`, as its presence byte followed by the
+/// bytes of the value, if any.
+fn to_bytes<T, const N: usize>(option: &Option<T>, buf: &mut [u8])
+where
+    T: Serializable<N>,
+{
+    if let Some(value) = option {
+        buf[0] = 1;
+        buf[1..].copy_from_slice(&value.to_bytes());
+    }
+}
+
+macro_rules! impl_serializable_option {
+    ($($size:literal)*) => {
+        $(
+            #[doc = concat!(
+                "Options of values of ", $size, " bytes. Options are ",
+                "implemented for values of up to 128 bytes, and of 192 and ",
+                "256 bytes, any other option is serialized through ",
+                "[`OptionN`]."
+            )]
+            impl<T> Serializable<{ $size + 1 }> for Option<T>
+            where
+                T: Serializable<$size>,
+                T::Error: InvalidData,
+            {
+                type Error = T::Error;
+
+                fn from_bytes(
+                    buf: &[u8; $size + 1],
+                ) -> Result<Self, Self::Error> {
+                    from_bytes::<T, $size>(buf[0], &buf[1..])
+                }
+
+                fn to_bytes(&self) -> [u8; $size + 1] {
+                    let mut buf = [0u8; $size + 1];
+                    to_bytes(self, &mut buf);
+                    buf
+                }
+            }
+        )*
+    };
+}
+
+impl_serializable_option!(
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27
+    28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52
+    53 54 55 56 57 58 59 60 61 62 63 64 65 66 67 68 69 70 71 72 73 74 75 76 77
+    78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96 97 98 99 100 101
+    102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119
+    120 121 122 123 124 125 126 127 128 192 256
+);
+
+/// An [`Option`] of a value of `N` bytes, implementing [`Serializable`] for
+/// any value size.
+///
+/// The option is encoded as the implementations for [`Option`] are, and `M`
+/// must be `N + 1`, which is checked at compile time. The
+/// [`option!`](crate::option) macro fills the sizes in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OptionN<T, const N: usize, const M: usize>(pub Option<T>);
+
+impl<T, const N: usize, const M: usize> OptionN<T, N, M> {
+    /// Returns the wrapped option.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize, const M: usize> From<Option<T>> for OptionN<T, N, M> {
+    fn from(option: Option<T>) -> Self {
+        Self(option)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Serializable<M> for OptionN<T, N, M>
+where
+    T: Serializable<N>,
+    T::Error: InvalidData,
+{
+    type Error = T::Error;
+
+    fn from_bytes(buf: &[u8; M]) -> Result<Self, Self::Error> {
+        const {
+            assert!(
+                M == N + 1,
+                "the option's size must be its value's size plus one"
+            )
+        };
+
+        from_bytes::<T, N>(buf[0], &buf[1..]).map(Self)
+    }
+
+    fn to_bytes(&self) -> [u8; M] {
+        const {
+            assert!(
+                M == N + 1,
+                "the option's size must be its value's size plus one"
+            )
+        };
+
+        let mut buf = [0u8; M];
+        to_bytes(&self.0, &mut buf);
+        buf
+    }
+}
+
+/// Names the [`OptionN`] wrapper for an option of the given type, computing
+/// the sizes of the value and of the option at compile time.
+///
+/// ```rust
+/// use dusk_bytes::Serializable;
+///
+/// // An optional signature of 512 bytes
+/// type Signature = dusk_bytes::option!([u8; 512]);
+///
+/// let signature = Signature::from(Some([7; 512]));
+/// assert_eq!(Signature::SIZE, 513);
+///
+/// let bytes = signature.to_bytes();
+/// assert_eq!(bytes[0], 1);
+/// assert_eq!(Signature::from_bytes(&bytes).unwrap(), signature);
+/// ```
+#[macro_export]
+macro_rules! option {
+    ($ty:ty) => {
+        $crate::OptionN<
+            $ty,
+            { $crate::__private::size_of(::core::marker::PhantomData::<$ty>) },
+            {
+                $crate::__private::size_of(::core::marker::PhantomData::<$ty>)
+                    + 1
+            },
+        >
+    };
+}
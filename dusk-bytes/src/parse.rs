@@ -4,7 +4,8 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use super::errors::{BadLength, InvalidChar};
+use super::ct;
+use super::errors::{BadLength, InvalidChar, OddLength, TrailingBytes};
 use super::serialize::Serializable;
 
 /// An optional trait used to parse a string slice for types that implements
@@ -14,11 +15,17 @@ use super::serialize::Serializable;
 /// consumer.
 pub trait ParseHexStr<const N: usize>: Serializable<N> {
     /// Parse a string slice as bytes hex representation and returns `
+    ///
+    /// The string slice may start with a `0x` or `0X` prefix. The lengths
+    /// reported in the errors count the hex characters only, while the
+    /// indices point at positions in the original string slice.
     fn from_hex_str(s: &str) -> Result<Self, Self::Error>
     where
         Self: Sized,
         Self::Error: BadLength + InvalidChar,
     {
+        let (s, offset) = strip_prefix(s);
+
         let expected = N * 2;
         if s.len() < expected {
             return Err(Self::Error::bad_length(s.len(), expected));
@@ -31,12 +38,15 @@ pub trait ParseHexStr<const N: usize>: Serializable<N> {
             let n: u8 = match (val(s[i]), val(s[i + 1])) {
                 (Some(h), Some(l)) => (h << 4) + l,
                 (None, _) => {
-                    return Err(Self::Error::invalid_char(s[i].into(), i));
+                    return Err(Self::Error::invalid_char(
+                        s[i].into(),
+                        offset + i,
+                    ));
                 }
                 (_, None) => {
                     return Err(Self::Error::invalid_char(
                         s[i + 1].into(),
-                        i + 1,
+                        offset + i + 1,
                     ));
                 }
             };
@@ -45,6 +55,112 @@ pub trait ParseHexStr<const N: usize>: Serializable<N> {
 
         Self::from_bytes(&bytes)
     }
+
+    /// Parse a string slice as bytes hex representation, as
+    /// [`ParseHexStr::from_hex_str`] does, in constant time.
+    ///
+    /// Meant for secret material, such as private keys: the hex characters
+    /// are converted without branching on their value, and all of them are
+    /// processed before an invalid character is reported. Only the length
+    /// of the string slice, and the index of the first invalid character
+    /// once reported, are not kept secret.
+    fn from_hex_str_ct(s: &str) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar,
+    {
+        let (s, offset) = strip_prefix(s);
+
+        let expected = N * 2;
+        if s.len() < expected {
+            return Err(Self::Error::bad_length(s.len(), expected));
+        }
+
+        let mut bytes = [0u8; N];
+        let s = s.as_bytes();
+
+        // All bits set once an invalid character is found
+        let mut invalid = 0u16;
+        let mut index = 0;
+
+        for (i, &c) in s[..expected].iter().enumerate() {
+            let n = ct::decode_nibble(c);
+            let bad = ((n as i16) >> 15) as u16;
+
+            index = ct::select(bad & !invalid, i, index);
+            invalid |= bad;
+
+            if i % 2 == 0 {
+                bytes[i / 2] = (n as u8) << 4;
+            } else {
+                bytes[i / 2] |= n as u8 & 0x0f;
+            }
+        }
+
+        if invalid != 0 {
+            return Err(Self::Error::invalid_char(
+                s[index].into(),
+                offset + index,
+            ));
+        }
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a string slice of exactly `N * 2` hex characters as bytes,
+    /// failing on any trailing character.
+    ///
+    /// A string slice with an odd number of characters is rejected as such,
+    /// before being checked to be too short or too long. As for
+    /// [`ParseHexStr::from_hex_str`], the optional `0x` or `0X` prefix is not
+    /// counted.
+    fn from_hex_str_exact(s: &str) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + InvalidChar + OddLength + TrailingBytes,
+    {
+        let (digits, _) = strip_prefix(s);
+
+        let expected = N * 2;
+        if digits.len() % 2 != 0 {
+            return Err(Self::Error::odd_length(digits.len()));
+        }
+        if digits.len() > expected {
+            return Err(Self::Error::trailing_bytes(digits.len(), expected));
+        }
+
+        Self::from_hex_str(s)
+    }
+}
+
+/// Strips the optional `0x` or `0X` prefix of a hex string slice, returning
+/// the hex characters and their offset in the original string slice.
+fn strip_prefix(s: &str) -> (&str, usize) {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(digits) => (digits, 2),
+        None => (s, 0),
+    }
+}
+
+/// Parse a string slice of exactly `N * 2` hex characters, after the optional
+/// `0x` or `0X` prefix, as the `FromStr` implementation of
+/// `#[derive(HexFromStr)]` does.
+///
+/// Unlike [`ParseHexStr::from_hex_str`], trailing characters are rejected,
+/// as a [`BadLength`] error, so that the error bounds stay the same.
+pub fn parse_hex_str<T, const N: usize>(s: &str) -> Result<T, T::Error>
+where
+    T: ParseHexStr<N>,
+    T::Error: BadLength + InvalidChar,
+{
+    let (digits, _) = strip_prefix(s);
+
+    let expected = N * 2;
+    if digits.len() != expected {
+        return Err(T::Error::bad_length(digits.len(), expected));
+    }
+
+    T::from_hex_str(s)
 }
 
 /// A constant funtion to parse a bytes string representing hexadecimals
@@ -53,8 +169,15 @@ pub trait ParseHexStr<const N: usize>: Serializable<N> {
 /// (e.g `[0xfe, 0x12]`); if a bigger destination buffer is provided, it will
 /// be padded with zeroes (e.g. `[0xfe, 0x12, 0xc6, 0x0, 0x0])
 ///
-/// If an invalid character is given, it will panic at compile time.
+/// If an invalid character, or an odd number of characters, is given, it will
+/// panic at compile time. See [`hex!`](crate::hex!) for a version inferring
+/// the size of the destination buffer.
 pub const fn hex<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    assert!(
+        N % 2 == 0,
+        "hex(): the input must have an even number of characters"
+    );
+
     let mut buffer = [0u8; M];
 
     let mut i = 0;
@@ -72,6 +195,102 @@ pub const fn hex<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
     buffer
 }
 
+/// Parse a hex string literal into bytes, inferring their number from the
+/// literal.
+///
+/// The literal may contain whitespace, and a `0x` or `0X` prefix at the start
+/// of each group of hex characters. An invalid character, or an odd number of
+/// hex characters, fails to compile.
+///
+/// ```rust
+/// use dusk_bytes::hex;
+///
+/// const KEY: [u8; 4] = hex!("dead beef");
+/// assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let prefixed = hex!("0xde 0xad 0xBE 0xEF");
+/// assert_eq!(prefixed, KEY);
+/// ```
+///
+/// ```rust,compile_fail
+/// // An odd number of hex characters
+/// let bytes = dusk_bytes::hex!("dea");
+/// ```
+///
+/// ```rust,compile_fail
+/// // An invalid character
+/// let bytes = dusk_bytes::hex!("dead-beef");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($s:literal) => {{
+        const LEN: usize = $crate::__private::hex_literal_len($s.as_bytes());
+        const BYTES: [u8; LEN] =
+            $crate::__private::parse_hex_literal::<LEN>($s.as_bytes());
+        BYTES
+    }};
+}
+
+/// Whether a `0x` or `0X` prefix starts at `s[i]`, at the start of a group of
+/// hex characters.
+const fn is_hex_prefix(s: &[u8], i: usize) -> bool {
+    (i == 0 || s[i - 1].is_ascii_whitespace())
+        && i + 1 < s.len()
+        && s[i] == b'0'
+        && (s[i + 1] == b'x' || s[i + 1] == b'X')
+}
+
+/// Returns the number of bytes of a [`hex!`](crate::hex!) literal, panicking
+/// on invalid characters and on an odd number of hex characters.
+pub const fn hex_literal_len(s: &[u8]) -> usize {
+    let mut digits = 0;
+
+    let mut i = 0;
+    while i < s.len() {
+        if is_hex_prefix(s, i) {
+            i += 2;
+            continue;
+        }
+        if !s[i].is_ascii_whitespace() {
+            if val(s[i]).is_none() {
+                panic!("hex!: invalid hex character in the literal");
+            }
+            digits += 1;
+        }
+        i += 1;
+    }
+
+    if digits % 2 != 0 {
+        panic!("hex!: the literal must have an even number of hex characters");
+    }
+    digits / 2
+}
+
+/// Parse a [`hex!`](crate::hex!) literal of `N` bytes, checked by
+/// [`hex_literal_len`].
+pub const fn parse_hex_literal<const N: usize>(s: &[u8]) -> [u8; N] {
+    let mut buffer = [0u8; N];
+    let mut digits = 0;
+
+    let mut i = 0;
+    while i < s.len() {
+        if is_hex_prefix(s, i) {
+            i += 2;
+            continue;
+        }
+        if let Some(n) = val(s[i]) {
+            if digits % 2 == 0 {
+                buffer[digits / 2] = n << 4;
+            } else {
+                buffer[digits / 2] |= n;
+            }
+            digits += 1;
+        }
+        i += 1;
+    }
+    buffer
+}
+
 const fn val(c: u8) -> Option<u8> {
     match c {
         b'A'..=b'F' => Some(c - b'A' + 10),
@@ -0,0 +1,304 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Length prefixed encoding of the allocated collections.
+//!
+//! The collections are encoded as their length, in the integer encoding
+//! picked by the [`LengthPrefix`] type parameter, followed by their content.
+//! The length prefix is read before allocating anything, and is checked
+//! against a maximum given by the caller, so that a malicious prefix can't
+//! make the decoder allocate more than what the caller is ready to.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::serialize::{read_exact, write_all};
+use crate::{
+    DeserializableSlice, DynSerializable, Error, Read, Serializable, U32Size,
+    U64Size, Varint, Write,
+};
+
+/// The integer encoding of the length prefix of a [`LengthPrefixed`] value.
+///
+/// Implemented by [`U32Size`] and [`U64Size`] for a fixed width little endian
+/// prefix, and by [`Varint<usize>`] for a LEB128 one.
+pub trait LengthPrefix {
+    /// The number of bytes the prefix of `len` is encoded into.
+    fn prefix_len(len: usize) -> usize;
+
+    /// Write the prefix of `len` into a writer, returning how many bytes were
+    /// written.
+    fn write_prefix<W>(len: usize, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write;
+
+    /// Read a length prefix from a reader.
+    fn read_prefix<R>(r: &mut R) -> Result<usize, Error>
+    where
+        R: Read;
+}
+
+impl LengthPrefix for U32Size {
+    fn prefix_len(_: usize) -> usize {
+        Self::SIZE
+    }
+
+    fn write_prefix<W>(len: usize, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        write_all(w, &Self::try_from(len)?.to_bytes())
+    }
+
+    fn read_prefix<R>(r: &mut R) -> Result<usize, Error>
+    where
+        R: Read,
+    {
+        Self::from_reader(r).map(Self::get)
+    }
+}
+
+impl LengthPrefix for U64Size {
+    fn prefix_len(_: usize) -> usize {
+        Self::SIZE
+    }
+
+    fn write_prefix<W>(len: usize, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        write_all(w, &Self::from(len).to_bytes())
+    }
+
+    fn read_prefix<R>(r: &mut R) -> Result<usize, Error>
+    where
+        R: Read,
+    {
+        Self::from_reader(r).map(Self::get)
+    }
+}
+
+impl LengthPrefix for Varint<usize> {
+    fn prefix_len(len: usize) -> usize {
+        Varint(len).encoded_len()
+    }
+
+    fn write_prefix<W>(len: usize, w: &mut W) -> Result<usize, Error>
+    where
+        W: Write,
+    {
+        Varint(len).write_to(w)
+    }
+
+    fn read_prefix<R>(r: &mut R) -> Result<usize, Error>
+    where
+        R: Read,
+    {
+        Self::read_from(r).map(|len| len.0)
+    }
+}
+
+/// Read a length prefix from a reader, checking it against `max_len`.
+fn read_len<P, R>(r: &mut R, max_len: usize) -> Result<usize, Error>
+where
+    P: LengthPrefix,
+    R: Read,
+{
+    let len = P::read_prefix(r)?;
+    if len > max_len {
+        return Err(Error::LengthLimit {
+            found: len,
+            max: max_len,
+        });
+    }
+    Ok(len)
+}
+
+/// Read `len` bytes from a reader.
+fn read_bytes<R>(r: &mut R, len: usize) -> Result<Vec<u8>, Error>
+where
+    R: Read,
+{
+    let mut bytes = alloc::vec![0u8; len];
+    read_exact::<_, Error>(r, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// The trait used to serialize collections as their length followed by their
+/// content, with the length prefix encoded as `P`.
+///
+/// Since the length prefix drives how much is allocated when decoding,
+/// [`LengthPrefixed::read_prefixed`] takes the maximum length accepted, and
+/// fails with [`Error::LengthLimit`] before allocating anything for a longer
+/// prefix.
+///
+/// As for [`DynSerializable`], the `Marker` type parameter only exists to
+/// implement the trait for the vectors of both fixed and variable size
+/// elements.
+///
+/// ```rust
+/// use dusk_bytes::{LengthPrefixed, Varint};
+///
+/// let numbers = vec![0x0102u16, 0x0304];
+///
+/// let mut buf = [0u8; 5];
+/// numbers
+///     .write_prefixed::<Varint<usize>, _>(&mut &mut buf[..])
+///     .unwrap();
+/// assert_eq!(buf, [2, 2, 1, 4, 3]);
+///
+/// let mut r = &buf[..];
+/// let decoded = Vec::<u16>::read_prefixed::<Varint<usize>, _>(&mut r, 16);
+/// assert_eq!(decoded.unwrap(), numbers);
+/// ```
+pub trait LengthPrefixed<Marker = ()> {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// The number of bytes [`LengthPrefixed::write_prefixed`] writes for
+    /// this value, prefix included.
+    fn prefixed_len<P>(&self) -> usize
+    where
+        P: LengthPrefix;
+
+    /// Serialize the length of [`Self`] followed by its content into a
+    /// writer, returning how many bytes were written.
+    fn write_prefixed<P, W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        P: LengthPrefix,
+        W: Write;
+
+    /// Deserialize the type reading its length and its content from a
+    /// reader, failing if the length is bigger than `max_len`.
+    /// The bytes read are removed from the reader.
+    fn read_prefixed<P, R>(
+        r: &mut R,
+        max_len: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        P: LengthPrefix,
+        R: Read,
+        Self: Sized;
+}
+
+/// Vectors are prefixed with their number of elements, `max_len` being the
+/// maximum number of elements accepted.
+///
+/// The elements are written and read one by one, `Vec<u8>` included. Byte
+/// strings are better converted to a `Box<[u8]>`, whose implementation writes
+/// and reads the bytes at once, with the same encoding.
+impl<T, M> LengthPrefixed<M> for Vec<T>
+where
+    T: DynSerializable<M>,
+    T::Error: From<Error>,
+{
+    type Error = T::Error;
+
+    fn prefixed_len<P>(&self) -> usize
+    where
+        P: LengthPrefix,
+    {
+        P::prefix_len(self.len())
+            + self.iter().map(T::encoded_len).sum::<usize>()
+    }
+
+    fn write_prefixed<P, W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        P: LengthPrefix,
+        W: Write,
+    {
+        let mut written = P::write_prefix(self.len(), w)?;
+        for element in self {
+            written += element.write_to(w)?;
+        }
+        Ok(written)
+    }
+
+    fn read_prefixed<P, R>(
+        r: &mut R,
+        max_len: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        P: LengthPrefix,
+        R: Read,
+    {
+        let len = read_len::<P, R>(r, max_len)?;
+        (0..len).map(|_| T::read_from(r)).collect()
+    }
+}
+
+/// Boxed slices are prefixed with their number of bytes, `max_len` being the
+/// maximum number of bytes accepted.
+///
+/// The encoding is the one of `Vec<u8>`, but the bytes are written and read
+/// at once rather than one by one: [`Vec::into_boxed_slice`] and
+/// [`Vec::from`] convert between the two.
+impl LengthPrefixed for Box<[u8]> {
+    type Error = Error;
+
+    fn prefixed_len<P>(&self) -> usize
+    where
+        P: LengthPrefix,
+    {
+        P::prefix_len(self.len()) + self.len()
+    }
+
+    fn write_prefixed<P, W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        P: LengthPrefix,
+        W: Write,
+    {
+        Ok(P::write_prefix(self.len(), w)? + write_all::<_, Error>(w, self)?)
+    }
+
+    fn read_prefixed<P, R>(
+        r: &mut R,
+        max_len: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        P: LengthPrefix,
+        R: Read,
+    {
+        let len = read_len::<P, R>(r, max_len)?;
+        read_bytes(r, len).map(Vec::into_boxed_slice)
+    }
+}
+
+/// Strings are prefixed with their number of bytes, `max_len` being the
+/// maximum number of bytes accepted. Decoding bytes that are not valid UTF-8
+/// fails with [`Error::InvalidData`].
+impl LengthPrefixed for String {
+    type Error = Error;
+
+    fn prefixed_len<P>(&self) -> usize
+    where
+        P: LengthPrefix,
+    {
+        P::prefix_len(self.len()) + self.len()
+    }
+
+    fn write_prefixed<P, W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        P: LengthPrefix,
+        W: Write,
+    {
+        Ok(P::write_prefix(self.len(), w)?
+            + write_all::<_, Error>(w, self.as_bytes())?)
+    }
+
+    fn read_prefixed<P, R>(
+        r: &mut R,
+        max_len: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        P: LengthPrefix,
+        R: Read,
+    {
+        let len = read_len::<P, R>(r, max_len)?;
+        String::from_utf8(read_bytes(r, len)?).map_err(|_| Error::InvalidData)
+    }
+}
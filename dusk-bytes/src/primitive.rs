@@ -4,8 +4,85 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use core::num::NonZero;
+
 use crate::{Error, Serializable};
 
+/// Wrapper to serialize a primitive as big endian.
+///
+/// The primitives implement [`Serializable`] as little endian; wrapping them
+/// in [`Be`] picks the big endian byte order instead.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Be<T>(pub T);
+
+/// Wrapper to serialize a primitive as little endian.
+///
+/// This is the byte order of the primitives' own [`Serializable`]
+/// implementation, [`Le`] makes it explicit at the type level.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Le<T>(pub T);
+
+/// Wrapper to serialize a float rejecting non canonical NaNs.
+///
+/// Floats implement [`Serializable`] preserving their exact bits, so the same
+/// NaN can have many encodings. Wrapped in [`CanonicalNan`], any NaN is
+/// encoded as the canonical one, the positive quiet NaN with no payload
+/// (`0x7fc0_0000` for `f32` and `0x7ff8_0000_0000_0000` for `f64`), and
+/// decoding any other NaN fails with [`Error::InvalidData`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct CanonicalNan<T>(pub T);
+
+/// A `usize` always serialized as a little endian `u64`, whatever the width
+/// of the platform's `usize`.
+///
+/// Decoding a value that doesn't fit in the platform's `usize` fails with
+/// [`Error::Overflow`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U64Size(usize);
+
+/// A `usize` always serialized as a little endian `u32`, whatever the width
+/// of the platform's `usize`.
+///
+/// Creating it from a `usize` that doesn't fit in a `u32`, or decoding a value
+/// that doesn't fit in the platform's `usize`, fails with [`Error::Overflow`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct U32Size(usize);
+
+/// An `isize` always serialized as a little endian `i64`, whatever the width
+/// of the platform's `isize`.
+///
+/// Decoding a value that doesn't fit in the platform's `isize` fails with
+/// [`Error::Overflow`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I64Size(isize);
+
+/// An `isize` always serialized as a little endian `i32`, whatever the width
+/// of the platform's `isize`.
+///
+/// Creating it from an `isize` that doesn't fit in an `i32`, or decoding a
+/// value that doesn't fit in the platform's `isize`, fails with
+/// [`Error::Overflow`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I32Size(isize);
+
+impl<T> From<T> for Be<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<T> for Le<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<T> for CanonicalNan<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
 macro_rules! impl_serializable {
     ($ty:ty) => {
         impl Serializable<{ core::mem::size_of::<$ty>() }> for $ty {
@@ -19,6 +96,30 @@ macro_rules! impl_serializable {
                 <$ty>::to_le_bytes(*self)
             }
         }
+
+        impl Serializable<{ core::mem::size_of::<$ty>() }> for Be<$ty> {
+            type Error = Error;
+
+            fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+                Ok(Self(<$ty>::from_be_bytes(*buf)))
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                <$ty>::to_be_bytes(self.0)
+            }
+        }
+
+        impl Serializable<{ core::mem::size_of::<$ty>() }> for Le<$ty> {
+            type Error = Error;
+
+            fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+                Ok(Self(<$ty>::from_le_bytes(*buf)))
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                <$ty>::to_le_bytes(self.0)
+            }
+        }
     };
 }
 
@@ -33,3 +134,176 @@ impl_serializable!(i16);
 impl_serializable!(i32);
 impl_serializable!(i64);
 impl_serializable!(i128);
+
+impl_serializable!(f32);
+impl_serializable!(f64);
+
+/// The bits of the canonical `f32` NaN. Hard-coded, since [`f32::NAN`] has no
+/// guaranteed bit pattern.
+const CANONICAL_NAN_F32: u32 = 0x7fc0_0000;
+
+/// The bits of the canonical `f64` NaN. Hard-coded, since [`f64::NAN`] has no
+/// guaranteed bit pattern.
+const CANONICAL_NAN_F64: u64 = 0x7ff8_0000_0000_0000;
+
+macro_rules! impl_serializable_canonical_nan {
+    ($ty:ty, $bits:expr) => {
+        impl Serializable<{ core::mem::size_of::<$ty>() }>
+            for CanonicalNan<$ty>
+        {
+            type Error = Error;
+
+            fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+                let value = <$ty>::from_le_bytes(*buf);
+
+                if value.is_nan() && value.to_bits() != $bits {
+                    return Err(Error::InvalidData);
+                }
+
+                Ok(Self(value))
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                if self.0.is_nan() {
+                    <$ty>::from_bits($bits).to_le_bytes()
+                } else {
+                    self.0.to_le_bytes()
+                }
+            }
+        }
+    };
+}
+
+impl_serializable_canonical_nan!(f32, CANONICAL_NAN_F32);
+impl_serializable_canonical_nan!(f64, CANONICAL_NAN_F64);
+
+macro_rules! impl_serializable_non_zero {
+    ($ty:ty) => {
+        impl Serializable<{ core::mem::size_of::<$ty>() }> for NonZero<$ty> {
+            type Error = Error;
+
+            fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+                Self::new(<$ty>::from_le_bytes(*buf)).ok_or(Error::InvalidData)
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                self.get().to_le_bytes()
+            }
+        }
+    };
+}
+
+impl_serializable_non_zero!(u8);
+impl_serializable_non_zero!(u16);
+impl_serializable_non_zero!(u32);
+impl_serializable_non_zero!(u64);
+impl_serializable_non_zero!(u128);
+
+impl_serializable_non_zero!(i8);
+impl_serializable_non_zero!(i16);
+impl_serializable_non_zero!(i32);
+impl_serializable_non_zero!(i64);
+impl_serializable_non_zero!(i128);
+
+/// `false` is encoded as `0` and `true` as `1`, any other byte is rejected.
+impl Serializable<1> for bool {
+    type Error = Error;
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        match buf[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidData),
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        [*self as u8]
+    }
+}
+
+/// A `char` is encoded as its little endian `u32` scalar value. Surrogates and
+/// values past [`char::MAX`] are rejected.
+impl Serializable<4> for char {
+    type Error = Error;
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        char::from_u32(u32::from_le_bytes(*buf)).ok_or(Error::InvalidData)
+    }
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        u32::from(*self).to_le_bytes()
+    }
+}
+
+// `U64Size` and `I64Size` rely on any `usize` and `isize` fitting in 64 bits.
+const _: () = assert!(core::mem::size_of::<usize>() <= 8);
+
+impl From<usize> for U64Size {
+    fn from(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl From<isize> for I64Size {
+    fn from(value: isize) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<usize> for U32Size {
+    type Error = Error;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u32::try_from(value)
+            .map(|_| Self(value))
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+impl TryFrom<isize> for I32Size {
+    type Error = Error;
+
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        i32::try_from(value)
+            .map(|_| Self(value))
+            .map_err(|_| Error::Overflow)
+    }
+}
+
+macro_rules! impl_serializable_size {
+    ($name:ident, $ty:ty, $repr:ty) => {
+        impl $name {
+            /// Returns the wrapped value.
+            pub const fn get(self) -> $ty {
+                self.0
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl Serializable<{ core::mem::size_of::<$repr>() }> for $name {
+            type Error = Error;
+
+            fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+                <$ty>::try_from(<$repr>::from_le_bytes(*buf))
+                    .map(Self)
+                    .map_err(|_| Error::Overflow)
+            }
+
+            fn to_bytes(&self) -> [u8; Self::SIZE] {
+                // The value is checked to fit when the wrapper is created
+                (self.0 as $repr).to_le_bytes()
+            }
+        }
+    };
+}
+
+impl_serializable_size!(U64Size, usize, u64);
+impl_serializable_size!(U32Size, usize, u32);
+impl_serializable_size!(I64Size, isize, i64);
+impl_serializable_size!(I32Size, isize, i32);
@@ -4,7 +4,7 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use super::errors::{BadLength, Error};
+use super::errors::{BadLength, Error, TrailingBytes};
 
 /// The core trait used to implement [`from_bytes`] and [`to_bytes`]
 pub trait Serializable<const N: usize> {
@@ -43,6 +43,20 @@ pub trait DeserializableSlice<const N: usize>: Serializable<N> {
         }
     }
 
+    /// Deserialize a slice of exactly `N` [`u8`] into [`Self`], failing on
+    /// any trailing byte
+    fn from_slice_exact(buf: &[u8]) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+        Self::Error: BadLength + TrailingBytes,
+    {
+        if buf.len() > N {
+            Err(Self::Error::trailing_bytes(buf.len(), N))
+        } else {
+            Self::from_slice(buf)
+        }
+    }
+
     /// Deserialize the type reading the bytes from a reader.
     /// The bytes read are removed from the reader.
     fn from_reader<R>(buf: &mut R) -> Result<Self, Self::Error>
@@ -52,8 +66,7 @@ pub trait DeserializableSlice<const N: usize>: Serializable<N> {
         Self::Error: BadLength,
     {
         let mut bytes = [0u8; N];
-        buf.read(&mut bytes)
-            .map_err(|_| Self::Error::bad_length(buf.capacity(), N))?;
+        read_exact(buf, &mut bytes)?;
 
         Self::from_bytes(&bytes)
     }
@@ -63,6 +76,188 @@ pub trait DeserializableSlice<const N: usize>: Serializable<N> {
 // [`Serializable`]
 impl<T, const N: usize> DeserializableSlice<N> for T where T: Serializable<N> {}
 
+/// An optional trait used to implement [`SerializableSlice::to_slice`] and
+/// [`SerializableSlice::to_writer`] on top of types that uses [`Serializable`]
+/// trait, the counterpart of [`DeserializableSlice`].
+/// Exactly `N` bytes are written, and a buffer too small to hold them is
+/// reported through [`BadLength`].
+pub trait SerializableSlice<const N: usize>: Serializable<N> {
+    /// Serialize [`Self`] into the first `N` bytes of a slice of [`u8`]
+    fn to_slice(&self, buf: &mut [u8]) -> Result<(), Self::Error>
+    where
+        Self::Error: BadLength,
+    {
+        if buf.len() < N {
+            Err(Self::Error::bad_length(buf.len(), N))
+        } else {
+            buf[..N].copy_from_slice(&self.to_bytes());
+            Ok(())
+        }
+    }
+
+    /// Serialize [`Self`] into a writer.
+    ///
+    /// The writer is called until all the `N` bytes are written, and a
+    /// writer accepting no more bytes is reported through [`BadLength`].
+    fn to_writer<W>(&self, w: &mut W) -> Result<(), Self::Error>
+    where
+        W: Write,
+        Self::Error: BadLength,
+    {
+        write_all(w, &self.to_bytes())?;
+
+        Ok(())
+    }
+}
+
+// Auto trait [`SerializableSlice`] for any type that implements
+// [`Serializable`]
+impl<T, const N: usize> SerializableSlice<N> for T where T: Serializable<N> {}
+
+/// The trait used to implement serialization for types whose encoding doesn't
+/// have a size known at compile time (e.g. vectors or byte strings), on top
+/// of [`Read`] and [`Write`].
+///
+/// Every type implementing [`Serializable`] also implements
+/// [`DynSerializable`], so that fixed and variable size types can be mixed
+/// freely. The `Marker` type parameter only exists to allow that blanket
+/// implementation: types implement the trait with the default `()` marker,
+/// while the [`Serializable`] ones use [`Fixed`]. Code generic over both
+/// kinds can be written as:
+///
+/// ```rust
+/// use dusk_bytes::{DynSerializable, Write};
+///
+/// fn send<T, M, W>(value: &T, w: &mut W) -> Result<usize, T::Error>
+/// where
+///     T: DynSerializable<M>,
+///     W: Write,
+/// {
+///     value.write_to(w)
+/// }
+/// ```
+pub trait DynSerializable<Marker = ()> {
+    /// The type returned in the event of a conversion error.
+    type Error;
+
+    /// The number of bytes [`DynSerializable::write_to`] writes for this
+    /// value.
+    fn encoded_len(&self) -> usize;
+
+    /// Serialize [`Self`] into a writer, returning how many bytes were
+    /// written.
+    fn write_to<W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        W: Write;
+
+    /// Deserialize the type reading the bytes from a reader.
+    /// The bytes read are removed from the reader.
+    fn read_from<R>(r: &mut R) -> Result<Self, Self::Error>
+    where
+        R: Read,
+        Self: Sized;
+}
+
+/// The marker used by the [`DynSerializable`] implementation of the types
+/// implementing [`Serializable<N>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<const N: usize>;
+
+impl<T, const N: usize> DynSerializable<Fixed<N>> for T
+where
+    T: Serializable<N>,
+    T::Error: BadLength,
+{
+    type Error = T::Error;
+
+    fn encoded_len(&self) -> usize {
+        N
+    }
+
+    fn write_to<W>(&self, w: &mut W) -> Result<usize, Self::Error>
+    where
+        W: Write,
+    {
+        write_all(w, &self.to_bytes())
+    }
+
+    fn read_from<R>(r: &mut R) -> Result<Self, Self::Error>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        Self::from_reader(r)
+    }
+}
+
+/// Write all of `buf` into `w`, calling it again after a partial write,
+/// and returning the number of bytes written.
+///
+/// A write of zero bytes is reported as a bad length of the bytes written so
+/// far, out of `buf.len()`.
+pub(crate) fn write_all<W, E>(w: &mut W, buf: &[u8]) -> Result<usize, E>
+where
+    W: Write + ?Sized,
+    E: BadLength,
+{
+    let mut written = 0;
+
+    while written < buf.len() {
+        match w.write(&buf[written..]) {
+            Ok(0) => return Err(E::bad_length(written, buf.len())),
+            Ok(n) => written += n,
+            Err(err) => return Err(bad_length(err, written, buf.len())),
+        }
+    }
+
+    Ok(written)
+}
+
+/// Fill all of `buf` from `r`, calling it again after a partial read, and
+/// returning the number of bytes read.
+///
+/// A read of zero bytes is reported as a bad length of the bytes read so far,
+/// out of `buf.len()`.
+pub(crate) fn read_exact<R, E>(r: &mut R, buf: &mut [u8]) -> Result<usize, E>
+where
+    R: Read + ?Sized,
+    E: BadLength,
+{
+    let len = buf.len();
+    let mut read = 0;
+
+    while read < len {
+        match r.read(&mut buf[read..]) {
+            Ok(0) => return Err(E::bad_length(read, len)),
+            Ok(n) => read += n,
+            Err(err) => return Err(bad_length(err, read, len)),
+        }
+    }
+
+    Ok(read)
+}
+
+/// Converts an error of a [`Read`] or a [`Write`] into `E`, after `found`
+/// bytes out of `expected` were transferred.
+///
+/// The lengths reported by an [`Error::BadLength`] for the rest of the
+/// buffer are offset by `found`, and an [`Error::Io`] is forwarded to
+/// [`BadLength::io_error`]. Any other error is reported as a bad length of
+/// `found` bytes out of `expected`.
+fn bad_length<E>(err: Error, found: usize, expected: usize) -> E
+where
+    E: BadLength,
+{
+    match err {
+        Error::BadLength {
+            found: rest_found,
+            expected: rest_expected,
+        } => E::bad_length(found + rest_found, found + rest_expected),
+        Error::Io => E::io_error(expected),
+        _ => E::bad_length(found, expected),
+    }
+}
+
 // The `Read` trait allows for reading bytes from a source.
 ///
 /// Implementors of the `Read` trait are called 'readers'.
@@ -77,6 +272,15 @@ pub trait Read {
     /// Pull some bytes from this source into the specified buffer, returning
     /// how many bytes were read.
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
+
+    /// Fill the whole buffer, calling [`Read::read`] again after a partial
+    /// read, and returning how many bytes were read.
+    ///
+    /// A read of zero bytes fails with [`Error::BadLength`], reporting the
+    /// bytes read so far.
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        read_exact(self, buf)
+    }
 }
 
 impl Read for &[u8] {
@@ -119,6 +323,15 @@ pub trait Write {
     /// the entire write may not succeed, or the write may also generate an
     /// error.
     fn write(&mut self, buf: &[u8]) -> Result<usize, Error>;
+
+    /// Write the whole buffer, calling [`Write::write`] again after a partial
+    /// write, and returning how many bytes were written.
+    ///
+    /// A write of zero bytes fails with [`Error::BadLength`], reporting the
+    /// bytes written so far.
+    fn write_all(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        write_all(self, buf)
+    }
 }
 
 impl Write for &mut [u8] {
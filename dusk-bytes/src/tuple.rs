@@ -0,0 +1,223 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! [`Serializable`] wrappers for tuples, encoding the members one after the
+//! other.
+//!
+//! The wrappers carry each member's size and the total size as const
+//! parameters, checked at compile time. The [`tuple!`](crate::tuple) macro
+//! fills them in.
+
+use crate::Serializable;
+use crate::derive::{read, write};
+
+macro_rules! impl_serializable_tuple {
+    (
+        $name:ident, $arity:literal;
+        $first:ident $n_first:ident $(, $ty:ident $n:ident)*
+    ) => {
+        #[doc = concat!(
+            "A tuple of ", $arity, " members implementing [`Serializable`]."
+        )]
+        ///
+        /// The members are encoded one after the other, and `N` must be the
+        /// sum of their sizes. The associated error is the one of the first
+        /// member, and the errors of the other members must be convertible
+        /// into it.
+        #[derive(
+            Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+        )]
+        pub struct $name<
+            $first,
+            $($ty,)*
+            const $n_first: usize,
+            $(const $n: usize,)*
+            const N: usize,
+        >(pub ($first, $($ty,)*));
+
+        impl<
+            $first,
+            $($ty,)*
+            const $n_first: usize,
+            $(const $n: usize,)*
+            const N: usize,
+        > $name<$first, $($ty,)* $n_first, $($n,)* N> {
+            /// Returns the wrapped tuple.
+            pub fn into_inner(self) -> ($first, $($ty,)*) {
+                self.0
+            }
+        }
+
+        impl<
+            $first,
+            $($ty,)*
+            const $n_first: usize,
+            $(const $n: usize,)*
+            const N: usize,
+        > From<($first, $($ty,)*)>
+            for $name<$first, $($ty,)* $n_first, $($n,)* N>
+        {
+            fn from(tuple: ($first, $($ty,)*)) -> Self {
+                Self(tuple)
+            }
+        }
+
+        impl<
+            $first,
+            $($ty,)*
+            const $n_first: usize,
+            $(const $n: usize,)*
+            const N: usize,
+        > Serializable<N> for $name<$first, $($ty,)* $n_first, $($n,)* N>
+        where
+            $first: Serializable<$n_first>,
+            $(
+                $ty: Serializable<$n>,
+                $first::Error: From<$ty::Error>,
+            )*
+        {
+            type Error = $first::Error;
+
+            #[allow(non_snake_case)]
+            fn from_bytes(buf: &[u8; N]) -> Result<Self, Self::Error> {
+                const {
+                    assert!(
+                        N == $n_first $(+ $n)*,
+                        "the tuple's size must be the sum of its members' sizes"
+                    )
+                };
+
+                let mut offset = 0;
+                let $first = read::<$first, $n_first>(buf, &mut offset)?;
+                $(let $ty = read::<$ty, $n>(buf, &mut offset)?;)*
+
+                Ok(Self(($first, $($ty,)*)))
+            }
+
+            #[allow(non_snake_case)]
+            fn to_bytes(&self) -> [u8; N] {
+                const {
+                    assert!(
+                        N == $n_first $(+ $n)*,
+                        "the tuple's size must be the sum of its members' sizes"
+                    )
+                };
+
+                let ($first, $($ty,)*) = &self.0;
+
+                let mut buf = [0u8; N];
+                let mut offset = 0;
+                write($first, &mut buf, &mut offset);
+                $(write($ty, &mut buf, &mut offset);)*
+                buf
+            }
+        }
+    };
+}
+
+impl_serializable_tuple!(Tuple2, 2; A NA, B NB);
+impl_serializable_tuple!(Tuple3, 3; A NA, B NB, C NC);
+impl_serializable_tuple!(Tuple4, 4; A NA, B NB, C NC, D ND);
+impl_serializable_tuple!(Tuple5, 5; A NA, B NB, C NC, D ND, E NE);
+impl_serializable_tuple!(Tuple6, 6; A NA, B NB, C NC, D ND, E NE, F NF);
+impl_serializable_tuple!(Tuple7, 7; A NA, B NB, C NC, D ND, E NE, F NF, G NG);
+impl_serializable_tuple!(
+    Tuple8, 8; A NA, B NB, C NC, D ND, E NE, F NF, G NG, H NH
+);
+impl_serializable_tuple!(
+    Tuple9, 9; A NA, B NB, C NC, D ND, E NE, F NF, G NG, H NH, I NI
+);
+impl_serializable_tuple!(
+    Tuple10, 10; A NA, B NB, C NC, D ND, E NE, F NF, G NG, H NH, I NI, J NJ
+);
+impl_serializable_tuple!(
+    Tuple11, 11;
+    A NA, B NB, C NC, D ND, E NE, F NF, G NG, H NH, I NI, J NJ, K NK
+);
+impl_serializable_tuple!(
+    Tuple12, 12;
+    A NA, B NB, C NC, D ND, E NE, F NF, G NG, H NH, I NI, J NJ, K NK, L NL
+);
+
+/// Names the `Tuple{K}` wrapper for the given member types, computing the
+/// sizes of the members and of the tuple at compile time.
+///
+/// ```rust
+/// use dusk_bytes::Serializable;
+///
+/// type Key = dusk_bytes::tuple!(u64, [u8; 32], u32);
+///
+/// let key = Key::from((1, [2; 32], 3));
+/// assert_eq!(Key::SIZE, 44);
+///
+/// let bytes = key.to_bytes();
+/// assert_eq!(Key::from_bytes(&bytes).unwrap(), key);
+/// ```
+#[macro_export]
+macro_rules! tuple {
+    (@wrapper $name:ident; $first:ty $(, $ty:ty)*) => {
+        $crate::$name<
+            $first,
+            $($ty,)*
+            { $crate::tuple!(@size $first) },
+            $({ $crate::tuple!(@size $ty) },)*
+            { $crate::tuple!(@size $first) $(+ $crate::tuple!(@size $ty))* },
+        >
+    };
+    (@size $ty:ty) => {
+        $crate::__private::size_of(::core::marker::PhantomData::<$ty>)
+    };
+    ($a:ty, $b:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple2; $a, $b)
+    };
+    ($a:ty, $b:ty, $c:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple3; $a, $b, $c)
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple4; $a, $b, $c, $d)
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple5; $a, $b, $c, $d, $e)
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple6; $a, $b, $c, $d, $e, $f)
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple7; $a, $b, $c, $d, $e, $f, $g)
+    };
+    ($a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty $(,)?) => {
+        $crate::tuple!(@wrapper Tuple8; $a, $b, $c, $d, $e, $f, $g, $h)
+    };
+    (
+        $a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty $(,)?
+    ) => {
+        $crate::tuple!(@wrapper Tuple9; $a, $b, $c, $d, $e, $f, $g, $h, $i)
+    };
+    (
+        $a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty,
+        $j:ty $(,)?
+    ) => {
+        $crate::tuple!(
+            @wrapper Tuple10; $a, $b, $c, $d, $e, $f, $g, $h, $i, $j
+        )
+    };
+    (
+        $a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty,
+        $j:ty, $k:ty $(,)?
+    ) => {
+        $crate::tuple!(
+            @wrapper Tuple11; $a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k
+        )
+    };
+    (
+        $a:ty, $b:ty, $c:ty, $d:ty, $e:ty, $f:ty, $g:ty, $h:ty, $i:ty,
+        $j:ty, $k:ty, $l:ty $(,)?
+    ) => {
+        $crate::tuple!(
+            @wrapper Tuple12; $a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l
+        )
+    };
+}
@@ -0,0 +1,183 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! LEB128 variable length encoding of the integers.
+//!
+//! Each byte carries 7 bits of the value, least significant first, and has
+//! its most significant bit set when more bytes follow. Signed integers are
+//! zigzag encoded first, so that small negative values are small as well.
+//!
+//! Only the shortest encoding of a value is accepted, so that a value always
+//! has exactly one representation.
+
+use crate::serialize::{read_exact, write_all};
+use crate::{DynSerializable, Error, Read, Write};
+
+/// Wrapper to serialize an integer as a LEB128 varint, zigzag encoded when
+/// signed, through [`DynSerializable`].
+///
+/// Decoding fails with [`Error::InvalidData`] when the encoding isn't the
+/// shortest one for the value, and with [`Error::Overflow`] when the value
+/// doesn't fit in the integer type. Since `usize` and `isize` have the width
+/// of the platform, such values may only be decoded on wider platforms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Varint<T>(pub T);
+
+impl<T> From<T> for Varint<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+/// The number of bytes `value` is encoded into.
+fn encoded_len(value: u128) -> usize {
+    let bits = u128::BITS - value.leading_zeros();
+    (bits.div_ceil(7) as usize).max(1)
+}
+
+/// The maximum number of bytes of an encoded `u128`.
+const MAX_LEN: usize = (u128::BITS as usize).div_ceil(7);
+
+/// Write `value` into `w`, returning the number of bytes written.
+///
+/// A writer accepting only part of the bytes is called again, and one
+/// accepting no more bytes is reported as [`Error::BadLength`].
+fn write_unsigned<W>(mut value: u128, w: &mut W) -> Result<usize, Error>
+where
+    W: Write,
+{
+    let mut buf = [0u8; MAX_LEN];
+    let mut len = 0;
+
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+
+    write_all(w, &buf[..len])
+}
+
+/// Read an integer of `bits` bits out of `r`.
+fn read_unsigned<R>(r: &mut R, bits: u32) -> Result<u128, Error>
+where
+    R: Read,
+{
+    let mut value = 0u128;
+    let mut shift = 0;
+
+    loop {
+        let mut byte = [0u8];
+        read_exact::<_, Error>(r, &mut byte)?;
+        let [byte] = byte;
+
+        let payload = (byte & 0x7f) as u128;
+        if bits - shift < 7 && payload >> (bits - shift) != 0 {
+            return Err(Error::Overflow);
+        }
+        value |= payload << shift;
+
+        if byte & 0x80 == 0 {
+            // A last byte of zero only adds zeroes to the value
+            if byte == 0 && shift > 0 {
+                return Err(Error::InvalidData);
+            }
+            return Ok(value);
+        }
+
+        shift += 7;
+        if shift >= bits {
+            return Err(Error::Overflow);
+        }
+    }
+}
+
+macro_rules! impl_varint_unsigned {
+    ($($ty:ty)*) => {
+        $(
+            impl DynSerializable for Varint<$ty> {
+                type Error = Error;
+
+                fn encoded_len(&self) -> usize {
+                    encoded_len(self.0 as u128)
+                }
+
+                fn write_to<W>(&self, w: &mut W) -> Result<usize, Self::Error>
+                where
+                    W: Write,
+                {
+                    write_unsigned(self.0 as u128, w)
+                }
+
+                fn read_from<R>(r: &mut R) -> Result<Self, Self::Error>
+                where
+                    R: Read,
+                {
+                    let value = read_unsigned(r, <$ty>::BITS)?;
+                    Ok(Self(value as $ty))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_varint_signed {
+    ($($ty:ty, $unsigned:ty;)*) => {
+        $(
+            impl Varint<$ty> {
+                /// Maps the value to an unsigned integer, interleaving the
+                /// negative and positive values: 0, -1, 1, -2, 2...
+                fn zigzag(&self) -> $unsigned {
+                    ((self.0 << 1) ^ (self.0 >> (<$ty>::BITS - 1))) as $unsigned
+                }
+
+                /// The inverse of [`Varint::zigzag`].
+                fn unzigzag(value: $unsigned) -> $ty {
+                    ((value >> 1) as $ty) ^ -((value & 1) as $ty)
+                }
+            }
+
+            impl DynSerializable for Varint<$ty> {
+                type Error = Error;
+
+                fn encoded_len(&self) -> usize {
+                    encoded_len(self.zigzag() as u128)
+                }
+
+                fn write_to<W>(&self, w: &mut W) -> Result<usize, Self::Error>
+                where
+                    W: Write,
+                {
+                    write_unsigned(self.zigzag() as u128, w)
+                }
+
+                fn read_from<R>(r: &mut R) -> Result<Self, Self::Error>
+                where
+                    R: Read,
+                {
+                    let value = read_unsigned(r, <$ty>::BITS)?;
+                    Ok(Self(Self::unzigzag(value as $unsigned)))
+                }
+            }
+        )*
+    };
+}
+
+impl_varint_unsigned!(u16 u32 u64 u128 usize);
+impl_varint_signed!(
+    i16, u16;
+    i32, u32;
+    i64, u64;
+    i128, u128;
+    isize, usize;
+);
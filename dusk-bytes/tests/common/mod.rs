@@ -4,7 +4,9 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use dusk_bytes::{BadLength, InvalidChar, Serializable};
+use dusk_bytes::{
+    BadLength, InvalidChar, OddLength, Serializable, TrailingBytes,
+};
 
 use dusk_bytes::HexDebug;
 #[derive(HexDebug)]
@@ -15,6 +17,10 @@ pub enum BeefError {
     InvalidBytes,
     UnexpectedEof,
     #[allow(dead_code)]
+    TrailingBytes,
+    #[allow(dead_code)]
+    OddLength(usize),
+    #[allow(dead_code)]
     CharNotValid(char, usize),
 }
 
@@ -47,3 +53,19 @@ impl InvalidChar for BeefError {
         Self::CharNotValid(ch, index)
     }
 }
+
+// Implementing the exact functions requires `Error` to implements
+// `TrailingBytes` too
+impl TrailingBytes for BeefError {
+    fn trailing_bytes(_found: usize, _expected: usize) -> Self {
+        Self::TrailingBytes
+    }
+}
+
+// Implementing `from_hex_str_exact` requires `Error` to implements `OddLength`
+// too
+impl OddLength for BeefError {
+    fn odd_length(found: usize) -> Self {
+        Self::OddLength(found)
+    }
+}
@@ -0,0 +1,525 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+mod common;
+use common::{Beef, BeefError};
+
+use dusk_bytes::{DeserializableSlice, Error, Serializable};
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Point {
+    x: u16,
+    y: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Pair(u8, i64);
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Limbs {
+    hash: [u8; 32],
+    limbs: [u64; 4],
+}
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Transfer {
+    amount: u64,
+    memo: Option<[u8; 4]>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serializable)]
+struct Segment {
+    from: Point,
+    to: Point,
+    tag: u8,
+}
+
+#[derive(Debug)]
+enum SteakError {
+    Beef(BeefError),
+    #[allow(dead_code)]
+    Bytes(Error),
+}
+
+impl From<BeefError> for SteakError {
+    fn from(err: BeefError) -> Self {
+        Self::Beef(err)
+    }
+}
+
+impl From<Error> for SteakError {
+    fn from(err: Error) -> Self {
+        Self::Bytes(err)
+    }
+}
+
+#[derive(Debug, Serializable)]
+#[bytes(error = "SteakError")]
+struct Steak {
+    weight: u16,
+    beef: Beef,
+}
+
+#[test]
+fn expected_size() {
+    assert_eq!(Point::SIZE, 6);
+    assert_eq!(Pair::SIZE, 9);
+    assert_eq!(Unit::SIZE, 0);
+    assert_eq!(Segment::SIZE, 13);
+    assert_eq!(Steak::SIZE, 4);
+    assert_eq!(Limbs::SIZE, 64);
+    assert_eq!(Transfer::SIZE, 13);
+}
+
+#[test]
+fn named_fields() -> Result<(), Error> {
+    let point = Point {
+        x: 0x0102,
+        y: 0x03040506,
+    };
+    let bytes = point.to_bytes();
+
+    assert_eq!(bytes, [0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+    assert_eq!(Point::from_bytes(&bytes)?, point);
+
+    Ok(())
+}
+
+#[test]
+fn unnamed_fields() -> Result<(), Error> {
+    let pair = Pair(0xff, -2);
+    let bytes = pair.to_bytes();
+
+    assert_eq!(
+        bytes,
+        [0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(Pair::from_bytes(&bytes)?, pair);
+
+    Ok(())
+}
+
+#[test]
+fn unit() -> Result<(), Error> {
+    assert_eq!(Unit.to_bytes(), []);
+    assert_eq!(Unit::from_bytes(&[])?, Unit);
+
+    Ok(())
+}
+
+#[test]
+fn nested() -> Result<(), Error> {
+    let segment = Segment {
+        from: Point { x: 1, y: 2 },
+        to: Point { x: 3, y: 4 },
+        tag: 5,
+    };
+    let bytes = segment.to_bytes();
+
+    assert_eq!(bytes, [1, 0, 2, 0, 0, 0, 3, 0, 4, 0, 0, 0, 5]);
+    assert_eq!(Segment::from_slice(&bytes)?, segment);
+
+    Ok(())
+}
+
+#[test]
+fn arrays() -> Result<(), Error> {
+    let limbs = Limbs {
+        hash: [0xaa; 32],
+        limbs: [1, 2, 3, 4],
+    };
+    let bytes = limbs.to_bytes();
+
+    assert_eq!(bytes[..32], [0xaa; 32]);
+    assert_eq!(bytes[32..40], [1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Limbs::from_bytes(&bytes)?, limbs);
+
+    Ok(())
+}
+
+#[test]
+fn options() -> Result<(), Error> {
+    let transfer = Transfer {
+        amount: 1,
+        memo: Some(*b"memo"),
+    };
+    let bytes = transfer.to_bytes();
+
+    assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 1, b'm', b'e', b'm', b'o']);
+    assert_eq!(Transfer::from_bytes(&bytes)?, transfer);
+
+    let transfer = Transfer {
+        amount: 1,
+        memo: None,
+    };
+    assert_eq!(transfer.to_bytes(), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    Ok(())
+}
+
+#[test]
+fn custom_error() {
+    let steak = Steak {
+        weight: 300,
+        beef: Beef {},
+    };
+    let bytes = steak.to_bytes();
+
+    assert_eq!(bytes, [0x2c, 0x01, 0xbe, 0xef]);
+    assert!(Steak::from_bytes(&bytes).is_ok());
+
+    let steak = Steak::from_bytes(&[0x2c, 0x01, 0xbe, 0xee]);
+    assert!(
+        matches!(steak, Err(SteakError::Beef(BeefError::InvalidBytes))),
+        "Field error converted into the struct error"
+    );
+}
+
+mod enums {
+    use super::*;
+    use dusk_bytes::{InvalidData, ParseHexStr};
+
+    #[derive(Debug, PartialEq, Eq, Serializable)]
+    enum Kind {
+        Transfer,
+        Stake = 5,
+        Unstake,
+        #[bytes(tag = 0x10)]
+        Withdraw,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serializable)]
+    #[bytes(tag_type = "u16")]
+    enum Payload {
+        Empty,
+        Amount(u64),
+        Move { from: u8, to: u32 },
+    }
+
+    #[derive(Debug)]
+    enum TagError {
+        Beef(BeefError),
+        UnknownTag,
+    }
+
+    impl From<BeefError> for TagError {
+        fn from(err: BeefError) -> Self {
+            Self::Beef(err)
+        }
+    }
+
+    impl InvalidData for TagError {
+        fn invalid_data() -> Self {
+            Self::UnknownTag
+        }
+    }
+
+    #[derive(Debug, Serializable)]
+    #[bytes(error = "TagError")]
+    enum Meal {
+        #[bytes(tag = 1)]
+        Beef(Beef),
+        #[bytes(tag = 2)]
+        Salad,
+    }
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(Kind::SIZE, 1);
+        assert_eq!(Payload::SIZE, 10);
+        assert_eq!(Meal::SIZE, 3);
+    }
+
+    #[test]
+    fn fieldless() -> Result<(), Error> {
+        let kinds = [
+            (Kind::Transfer, 0),
+            (Kind::Stake, 5),
+            (Kind::Unstake, 6),
+            (Kind::Withdraw, 0x10),
+        ];
+
+        for (kind, tag) in kinds {
+            assert_eq!(kind.to_bytes(), [tag]);
+            assert_eq!(Kind::from_bytes(&[tag])?, kind);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn data_carrying() -> Result<(), Error> {
+        let payloads = [
+            (Payload::Empty, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            (Payload::Amount(0x0102), [1, 0, 2, 1, 0, 0, 0, 0, 0, 0]),
+            (
+                Payload::Move {
+                    from: 7,
+                    to: 0x0304,
+                },
+                [2, 0, 7, 4, 3, 0, 0, 0, 0, 0],
+            ),
+        ];
+
+        for (payload, bytes) in payloads {
+            assert_eq!(payload.to_bytes(), bytes);
+            assert_eq!(Payload::from_bytes(&bytes)?, payload);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_tag() {
+        assert_eq!(Kind::from_bytes(&[1]), Err(Error::InvalidData));
+        assert_eq!(
+            Payload::from_bytes(&[3, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(Error::InvalidData)
+        );
+        // The tag is little endian
+        assert_eq!(
+            Payload::from_bytes(&[0, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn non_zero_padding() {
+        assert_eq!(
+            Payload::from_bytes(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+            Err(Error::InvalidData)
+        );
+        assert_eq!(
+            Payload::from_bytes(&[2, 0, 7, 4, 3, 0, 0, 0, 1, 0]),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        assert!(Meal::from_bytes(&[1, 0xbe, 0xef]).is_ok());
+        assert!(Meal::from_bytes(&[2, 0, 0]).is_ok());
+
+        assert!(matches!(
+            Meal::from_bytes(&[0, 0xbe, 0xef]),
+            Err(TagError::UnknownTag)
+        ));
+        assert!(matches!(
+            Meal::from_bytes(&[1, 0xbe, 0xee]),
+            Err(TagError::Beef(BeefError::InvalidBytes))
+        ));
+        assert!(matches!(
+            Meal::from_bytes(&[2, 0xbe, 0xef]),
+            Err(TagError::UnknownTag)
+        ));
+    }
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!(Kind::from_hex_str("10"), Ok(Kind::Withdraw));
+    }
+}
+
+mod fields {
+    use super::*;
+
+    mod reversed {
+        use dusk_bytes::Error;
+
+        pub fn to_bytes(value: &[u8; 3]) -> [u8; 3] {
+            [value[2], value[1], value[0]]
+        }
+
+        pub fn from_bytes(buf: &[u8; 3]) -> Result<[u8; 3], Error> {
+            if buf == &[0; 3] {
+                return Err(Error::InvalidData);
+            }
+            Ok([buf[2], buf[1], buf[0]])
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serializable)]
+    struct Account {
+        #[bytes(be)]
+        nonce: u32,
+        balance: u16,
+        #[bytes(skip)]
+        cached: Option<u64>,
+        #[bytes(with = "reversed")]
+        id: [u8; 3],
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serializable)]
+    enum Entry {
+        Cached(#[bytes(skip)] u64, u8),
+        Big {
+            #[bytes(be)]
+            value: i16,
+        },
+    }
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(Account::SIZE, 9);
+        assert_eq!(Entry::SIZE, 3);
+    }
+
+    #[test]
+    fn attributes() -> Result<(), Error> {
+        let account = Account {
+            nonce: 0x01020304,
+            balance: 0x0506,
+            cached: Some(42),
+            id: [7, 8, 9],
+        };
+        let bytes = account.to_bytes();
+
+        assert_eq!(bytes, [1, 2, 3, 4, 6, 5, 9, 8, 7]);
+        assert_eq!(
+            Account::from_bytes(&bytes)?,
+            Account {
+                cached: None,
+                ..account
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_error() {
+        assert_eq!(
+            Account::from_bytes(&[1, 2, 3, 4, 6, 5, 0, 0, 0]),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn enum_variants() -> Result<(), Error> {
+        let entry = Entry::Cached(42, 3);
+        assert_eq!(entry.to_bytes(), [0, 3, 0]);
+        assert_eq!(Entry::from_bytes(&[0, 3, 0])?, Entry::Cached(0, 3));
+
+        let entry = Entry::Big { value: -2 };
+        assert_eq!(entry.to_bytes(), [1, 0xff, 0xfe]);
+        assert_eq!(Entry::from_bytes(&[1, 0xff, 0xfe])?, entry);
+
+        Ok(())
+    }
+}
+
+mod result_alias {
+    use super::*;
+    use dusk_bytes::HexFromStr;
+
+    // Shadows the prelude's `Result`, which the derived code must not rely on
+    type Result<T> = core::result::Result<T, Error>;
+
+    #[derive(Debug, PartialEq, Eq, Serializable, HexFromStr)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Serializable)]
+    enum Shape {
+        Dot(Point),
+        Nothing,
+    }
+
+    #[test]
+    fn derive() -> Result<()> {
+        let shape = Shape::Dot(Point { x: 1, y: 2 });
+        assert_eq!(Shape::from_bytes(&shape.to_bytes())?, shape);
+        assert_eq!(Shape::from_bytes(&[2; 9]), Err(Error::InvalidData));
+
+        let point: Point = "0100000002000000".parse()?;
+        assert_eq!(point, Point { x: 1, y: 2 });
+
+        Ok(())
+    }
+}
+
+mod hex_str {
+    use super::*;
+    use dusk_bytes::{HexDisplay, HexFromStr, hex};
+
+    #[derive(Debug, PartialEq, Eq, Serializable, HexDisplay, HexFromStr)]
+    struct Id([u8; 4]);
+
+    #[derive(Debug, PartialEq, Eq, Serializable, HexDisplay, HexFromStr)]
+    #[hex(error = "SteakError")]
+    struct Cut {
+        #[bytes(be)]
+        weight: u16,
+    }
+
+    #[test]
+    fn display() {
+        let id = Id(hex!("deadbeef"));
+
+        assert_eq!(id.to_string(), "deadbeef");
+        assert_eq!(format!("{id:#}"), "0xdeadbeef");
+        assert_eq!(Cut { weight: 0x0102 }.to_string(), "0102");
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Error> {
+        let id = Id(hex!("deadbeef"));
+
+        assert_eq!(id.to_string().parse::<Id>()?, id);
+        assert_eq!(format!("{id:#}").parse::<Id>()?, id);
+        assert_eq!("DEADBEEF".parse::<Id>()?, id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "dead".parse::<Id>(),
+            Err(Error::BadLength {
+                found: 4,
+                expected: 8
+            })
+        );
+        assert_eq!(
+            "deadbeeg".parse::<Id>(),
+            Err(Error::InvalidChar { ch: 'g', index: 7 })
+        );
+
+        // Trailing characters are rejected, unlike with `from_hex_str`
+        assert_eq!(
+            "deadbeef!!junk".parse::<Id>(),
+            Err(Error::BadLength {
+                found: 14,
+                expected: 8
+            })
+        );
+        assert_eq!(
+            "0xdeadbeef00".parse::<Id>(),
+            Err(Error::BadLength {
+                found: 10,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        assert!(matches!("0102".parse::<Cut>(), Ok(Cut { weight: 0x0102 })));
+        assert!(matches!(
+            "01".parse::<Cut>(),
+            Err(SteakError::Bytes(Error::BadLength {
+                found: 2,
+                expected: 4
+            }))
+        ));
+    }
+}
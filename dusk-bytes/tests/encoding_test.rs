@@ -0,0 +1,746 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+mod common;
+use common::{Beef, BeefError};
+
+use dusk_bytes::Error;
+
+mod base64 {
+    use super::*;
+    use dusk_bytes::{Base64, ParseBase64Str, ToBase64};
+
+    #[test]
+    fn rfc_vectors() -> Result<(), Error> {
+        let mut buf = [0u8; 8];
+
+        macro_rules! check {
+            ($input:literal, $padded:literal, $unpadded:literal) => {
+                let input = *$input;
+                assert_eq!(
+                    input.to_base64(&mut buf, Base64::Standard),
+                    $padded
+                );
+                assert_eq!(
+                    input.to_base64(&mut buf, Base64::StandardNoPad),
+                    $unpadded
+                );
+                assert_eq!(
+                    ParseBase64Str::from_base64_str($padded, Base64::Standard),
+                    Ok(input)
+                );
+                assert_eq!(
+                    ParseBase64Str::from_base64_str(
+                        $unpadded,
+                        Base64::StandardNoPad
+                    ),
+                    Ok(input)
+                );
+            };
+        }
+
+        check!(b"", "", "");
+        check!(b"f", "Zg==", "Zg");
+        check!(b"fo", "Zm8=", "Zm8");
+        check!(b"foo", "Zm9v", "Zm9v");
+        check!(b"foob", "Zm9vYg==", "Zm9vYg");
+        check!(b"fooba", "Zm9vYmE=", "Zm9vYmE");
+        check!(b"foobar", "Zm9vYmFy", "Zm9vYmFy");
+
+        Ok(())
+    }
+
+    #[test]
+    fn alphabets() -> Result<(), Error> {
+        let bytes = [0xfb, 0xff, 0xbf];
+        let mut buf = [0u8; 4];
+
+        assert_eq!(bytes.to_base64(&mut buf, Base64::Standard), "+/+/");
+        assert_eq!(bytes.to_base64(&mut buf, Base64::UrlSafe), "-_-_");
+        assert_eq!(<[u8; 3]>::from_base64_str("-_-_", Base64::UrlSafe)?, bytes);
+
+        assert_eq!(
+            <[u8; 3]>::from_base64_str("-_-_", Base64::Standard),
+            Err(Error::InvalidChar { ch: '-', index: 0 })
+        );
+        assert_eq!(
+            <[u8; 3]>::from_base64_str("+/+/", Base64::UrlSafeNoPad),
+            Err(Error::InvalidChar { ch: '+', index: 0 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_padding() {
+        // Missing padding
+        assert_eq!(
+            <[u8; 1]>::from_base64_str("Zg", Base64::Standard),
+            Err(Error::BadLength {
+                found: 2,
+                expected: 4
+            })
+        );
+        // Unexpected padding
+        assert_eq!(
+            <[u8; 1]>::from_base64_str("Zg==", Base64::StandardNoPad),
+            Err(Error::BadLength {
+                found: 4,
+                expected: 2
+            })
+        );
+        // Misplaced padding
+        assert_eq!(
+            <[u8; 1]>::from_base64_str("Z=g=", Base64::Standard),
+            Err(Error::InvalidChar { ch: '=', index: 1 })
+        );
+        assert_eq!(
+            <[u8; 1]>::from_base64_str("Zg=A", Base64::Standard),
+            Err(Error::InvalidChar { ch: 'A', index: 3 })
+        );
+    }
+
+    #[test]
+    fn canonical_trailing_bits() {
+        // "Zh" decodes to "f" as well, with non-zero trailing bits
+        assert_eq!(
+            <[u8; 1]>::from_base64_str("Zh==", Base64::Standard),
+            Err(Error::InvalidChar { ch: 'h', index: 1 })
+        );
+        assert_eq!(
+            <[u8; 2]>::from_base64_str("Zm9", Base64::StandardNoPad),
+            Err(Error::InvalidChar { ch: '9', index: 2 })
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        let mut buf = [0u8; 4];
+        assert_eq!(Beef {}.to_base64(&mut buf, Base64::Standard), "vu8=");
+
+        assert!(Beef::from_base64_str("vu8=", Base64::Standard).is_ok());
+
+        let beef = Beef::from_base64_str("vu*=", Base64::Standard);
+        assert!(matches!(beef, Err(BeefError::CharNotValid('*', 2))));
+
+        let beef = Beef::from_base64_str("AAA=", Base64::Standard);
+        assert!(matches!(beef, Err(BeefError::InvalidBytes)));
+    }
+
+    #[test]
+    fn bigger_buffer() {
+        let mut buf = [0xaa; 64];
+        let s = 0x0102030405060708_u64.to_base64(&mut buf, Base64::UrlSafe);
+        assert_eq!(s, "CAcGBQQDAgE=");
+        assert_eq!(
+            u64::from_base64_str(s, Base64::UrlSafe),
+            Ok(0x0102030405060708)
+        );
+    }
+}
+
+mod base58 {
+    use super::*;
+    use dusk_bytes::{ParseBase58Str, ToBase58};
+
+    #[test]
+    fn vectors() -> Result<(), Error> {
+        let mut buf = [0u8; 16];
+
+        macro_rules! check {
+            ($input:expr, $plain:literal, $check:literal) => {
+                let input = $input;
+                assert_eq!(input.to_base58(&mut buf), $plain);
+                assert_eq!(input.to_base58check(&mut buf), $check);
+                assert_eq!(ParseBase58Str::from_base58_str($plain), Ok(input));
+                assert_eq!(
+                    ParseBase58Str::from_base58check_str($check),
+                    Ok(input)
+                );
+            };
+        }
+
+        check!(*b"hello", "Cn8eVZg", "2L5B5yqsVG8Vt");
+        check!([0u8, 0, 0, 1], "1112", "111E1CgqW");
+        check!(
+            [0u8, 0, 0, 0x28, 0x7f, 0xb4, 0xcd],
+            "111233QC4",
+            "1117mtbcnHMjRe"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn zeroed_key() -> Result<(), Error> {
+        let key = [0u8; 32];
+
+        let mut buf = [0u8; <[u8; 32]>::BASE58_LEN];
+        let s = key.to_base58(&mut buf);
+        assert_eq!(s, "11111111111111111111111111111111");
+        assert_eq!(<[u8; 32]>::from_base58_str(s)?, key);
+
+        let mut buf = [0u8; <[u8; 32]>::BASE58CHECK_LEN];
+        let s = key.to_base58check(&mut buf);
+        assert_eq!(s, "11111111111111111111111111111111273Yts");
+        assert_eq!(<[u8; 32]>::from_base58check_str(s)?, key);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_chars() {
+        // `0`, `O`, `I` and `l` are not part of the alphabet
+        for (s, ch) in [("Cn0eVZg", '0'), ("CnOeVZg", 'O'), ("CnleVZg", 'l')] {
+            assert_eq!(
+                <[u8; 5]>::from_base58_str(s),
+                Err(Error::InvalidChar { ch, index: 2 })
+            );
+        }
+        assert_eq!(
+            <[u8; 5]>::from_base58check_str("2L5B5yqsVG8V+"),
+            Err(Error::InvalidChar { ch: '+', index: 12 })
+        );
+    }
+
+    #[test]
+    fn bad_length() {
+        assert_eq!(
+            <[u8; 4]>::from_base58_str("112"),
+            Err(Error::BadLength {
+                found: 3,
+                expected: 4
+            })
+        );
+        assert_eq!(
+            <[u8; 4]>::from_base58_str("11112"),
+            Err(Error::BadLength {
+                found: 5,
+                expected: 4
+            })
+        );
+        // Too big a value for 4 bytes
+        assert_eq!(
+            <[u8; 4]>::from_base58_str("zzzzzz"),
+            Err(Error::BadLength {
+                found: 5,
+                expected: 4
+            })
+        );
+        // The checksum is counted
+        assert_eq!(
+            <[u8; 4]>::from_base58check_str("Cn8eVZg"),
+            Err(Error::BadLength {
+                found: 5,
+                expected: 8
+            })
+        );
+    }
+
+    #[test]
+    fn bad_checksum() {
+        assert_eq!(
+            <[u8; 5]>::from_base58check_str("2L5B5yqsVG8Vu"),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        let mut buf = [0u8; 4];
+        assert_eq!(Beef {}.to_base58(&mut buf), "FXk");
+
+        assert!(Beef::from_base58_str("FXk").is_ok());
+
+        let beef = Beef::from_base58_str("FX0");
+        assert!(matches!(beef, Err(BeefError::CharNotValid('0', 2))));
+
+        let beef = Beef::from_base58_str("1B");
+        assert!(matches!(beef, Err(BeefError::InvalidBytes)));
+    }
+}
+
+mod bech32 {
+    use super::*;
+    use dusk_bytes::{Bech32, ParseBech32Str, ToBech32};
+
+    const KEY: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+    #[test]
+    fn bip_vectors() -> Result<(), Error> {
+        // BIP-173
+        assert_eq!(
+            <[u8; 0]>::from_bech32_str("A12UEL5L", "a", Bech32::Original)?,
+            []
+        );
+
+        // BIP-350
+        assert_eq!(
+            <[u8; 0]>::from_bech32_str("a1lqfn3a", "a", Bech32::Modified)?,
+            []
+        );
+        assert_eq!(
+            <[u8; 0]>::from_bech32_str("A1LQFN3A", "A", Bech32::Modified)?,
+            []
+        );
+
+        let s = "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx";
+        let bytes = <[u8; 20]>::from_bech32_str(s, "abcdef", Bech32::Modified)?;
+        assert_eq!(
+            bytes,
+            dusk_bytes::hex!("ffbbcdeb38bdab49ca307b9ac5a928398a418820")
+        );
+
+        let mut buf = [0u8; 64];
+        assert_eq!(bytes.to_bech32("abcdef", &mut buf, Bech32::Modified)?, s);
+
+        Ok(())
+    }
+
+    #[test]
+    fn variants() -> Result<(), Error> {
+        let mut buf = [0u8; Bech32::encoded_len("dusk", 4)];
+
+        let s = KEY.to_bech32("dusk", &mut buf, Bech32::Original)?;
+        assert_eq!(s, "dusk1m6kmamcklqews");
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(s, "dusk", Bech32::Original)?,
+            KEY
+        );
+        assert!(
+            <[u8; 4]>::from_bech32_str(s, "dusk", Bech32::Modified).is_err()
+        );
+
+        let s = KEY.to_bech32("dusk", &mut buf, Bech32::Modified)?;
+        assert_eq!(s, "dusk1m6kmamcrrs4tj");
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(s, "dusk", Bech32::Modified)?,
+            KEY
+        );
+        assert!(
+            <[u8; 4]>::from_bech32_str(s, "dusk", Bech32::Original).is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn case() -> Result<(), Error> {
+        let mut buf = [0u8; 32];
+        let s = KEY.to_bech32("DUSK", &mut buf, Bech32::Modified)?;
+        assert_eq!(s, "dusk1m6kmamcrrs4tj");
+
+        let parsed = <[u8; 4]>::from_bech32_str(
+            "DUSK1M6KMAMCRRS4TJ",
+            "dusk",
+            Bech32::Modified,
+        )?;
+        assert_eq!(parsed, KEY);
+
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "DUSK1m6kmamcrrs4tj",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'm', index: 5 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hrp_mismatch() {
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusx1m6kmamcrrs4tj",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'x', index: 3 })
+        );
+        // Missing separator
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "duskm6kmamcrrs4tj",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'm', index: 4 })
+        );
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str("dus", "dusk", Bech32::Modified),
+            Err(Error::BadLength {
+                found: 3,
+                expected: 18
+            })
+        );
+    }
+
+    #[test]
+    fn data_errors() {
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kmamcrrs4t",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::BadLength {
+                found: 17,
+                expected: 18
+            })
+        );
+        // `b` is not part of the charset
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kbamcrrs4tj",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'b', index: 8 })
+        );
+        // Non-zero unused bits, with a valid checksum
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kmame74yqkq",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'e', index: 11 })
+        );
+    }
+
+    #[test]
+    fn error_position() {
+        // A substituted data character
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kqamcrrs4tj",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'q', index: 8 })
+        );
+        // A substituted checksum character
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kmamcrrs4tz",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidChar { ch: 'z', index: 17 })
+        );
+        // Swapped characters are detected, but not located
+        assert_eq!(
+            <[u8; 4]>::from_bech32_str(
+                "dusk1m6kmamcrrs4jt",
+                "dusk",
+                Bech32::Modified
+            ),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn hrp_errors() {
+        let mut buf = [0u8; 18];
+        assert_eq!(
+            KEY.to_bech32("", &mut buf, Bech32::Modified),
+            Err(Error::BadLength {
+                found: 0,
+                expected: 1
+            })
+        );
+        assert_eq!(
+            KEY.to_bech32("du sk", &mut buf, Bech32::Modified),
+            Err(Error::InvalidChar { ch: ' ', index: 2 })
+        );
+        assert_eq!(
+            KEY.to_bech32("dusky", &mut buf, Bech32::Modified),
+            Err(Error::BadLength {
+                found: 18,
+                expected: 19
+            })
+        );
+
+        let long = "a".repeat(84);
+        let mut buf = [0u8; 102];
+        assert_eq!(
+            KEY.to_bech32(&long, &mut buf, Bech32::Modified),
+            Err(Error::BadLength {
+                found: 84,
+                expected: 83
+            })
+        );
+        let s = KEY.to_bech32(&long[..83], &mut buf, Bech32::Modified);
+        assert_eq!(s.map(str::len), Ok(97));
+    }
+
+    #[test]
+    fn hrp_errors_on_parse() {
+        let parse =
+            |s, hrp| <[u8; 4]>::from_bech32_str(s, hrp, Bech32::Modified);
+
+        assert_eq!(
+            parse("1m6kmamcrrs4tj", ""),
+            Err(Error::BadLength {
+                found: 0,
+                expected: 1
+            })
+        );
+        assert_eq!(
+            parse("du sk1m6kmamcrrs4tj", "du sk"),
+            Err(Error::InvalidChar { ch: ' ', index: 2 })
+        );
+
+        let long = "a".repeat(84);
+        assert_eq!(
+            parse(&long, &long),
+            Err(Error::BadLength {
+                found: 84,
+                expected: 83
+            })
+        );
+    }
+
+    #[test]
+    fn longer_than_90_characters() -> Result<(), Error> {
+        let key = [0xab; 64];
+        let mut buf = [0u8; Bech32::encoded_len("dusk", 64)];
+        let s = key.to_bech32("dusk", &mut buf, Bech32::Modified)?;
+        assert_eq!(s.len(), 114);
+        assert_eq!(
+            <[u8; 64]>::from_bech32_str(s, "dusk", Bech32::Modified)?,
+            key
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_error() {
+        let mut buf = [0u8; 16];
+        let s = Beef {}.to_bech32("dusk", &mut buf, Bech32::Original);
+        assert_eq!(s.ok(), Some("dusk1hmhsgmxddt"));
+
+        let s = Beef {}.to_bech32("dusk\n", &mut buf, Bech32::Original);
+        assert!(matches!(s, Err(BeefError::CharNotValid('\n', 4))));
+    }
+}
+
+mod base32 {
+    use super::*;
+    use dusk_bytes::{Base32, ParseBase32Str, ToBase32};
+
+    #[test]
+    fn rfc_vectors() -> Result<(), Error> {
+        let mut buf = [0u8; 16];
+
+        macro_rules! check {
+            ($input:literal, $padded:literal, $unpadded:literal) => {
+                let input = *$input;
+                assert_eq!(input.to_base32(&mut buf, Base32::Rfc4648), $padded);
+                assert_eq!(
+                    input.to_base32(&mut buf, Base32::Rfc4648NoPad),
+                    $unpadded
+                );
+                assert_eq!(
+                    ParseBase32Str::from_base32_str($padded, Base32::Rfc4648),
+                    Ok(input)
+                );
+                assert_eq!(
+                    ParseBase32Str::from_base32_str(
+                        $unpadded,
+                        Base32::Rfc4648NoPad
+                    ),
+                    Ok(input)
+                );
+            };
+        }
+
+        check!(b"", "", "");
+        check!(b"f", "MY======", "MY");
+        check!(b"fo", "MZXQ====", "MZXQ");
+        check!(b"foo", "MZXW6===", "MZXW6");
+        check!(b"foob", "MZXW6YQ=", "MZXW6YQ");
+        check!(b"fooba", "MZXW6YTB", "MZXW6YTB");
+        check!(b"foobar", "MZXW6YTBOI======", "MZXW6YTBOI");
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_insensitive() -> Result<(), Error> {
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("mzxw6ytboi======", Base32::Rfc4648)?,
+            *b"foobar"
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("csqpyRK1e8", Base32::Crockford)?,
+            *b"foobar"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn crockford() -> Result<(), Error> {
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            b"foobar".to_base32(&mut buf, Base32::Crockford),
+            "CSQPYRK1E8"
+        );
+        assert_eq!(
+            [0u8, 1, 2, 3, 4].to_base32(&mut buf, Base32::Crockford),
+            "000G40R4"
+        );
+
+        // `O` is read as `0`, `I` and `L` as `1`
+        assert_eq!(
+            <[u8; 5]>::from_base32_str("oO0G40R4", Base32::Crockford)?,
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPYRKIE8", Base32::Crockford)?,
+            *b"foobar"
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPYRKlE8", Base32::Crockford)?,
+            *b"foobar"
+        );
+
+        // `U` is not part of the alphabet
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPYUK1E8", Base32::Crockford),
+            Err(Error::InvalidChar { ch: 'U', index: 5 })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn crockford_hyphens() -> Result<(), Error> {
+        // Hyphens are ignored wherever they are, and don't count in the
+        // length
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPY-RK1E8", Base32::Crockford)?,
+            *b"foobar"
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("-CS-QP--YRK1E8-", Base32::Crockford)?,
+            *b"foobar"
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPY-K1E8", Base32::Crockford),
+            Err(Error::BadLength {
+                found: 9,
+                expected: 10
+            })
+        );
+
+        // The positions are the ones in the string, hyphens included
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPY-UK1E8", Base32::Crockford),
+            Err(Error::InvalidChar { ch: 'U', index: 6 })
+        );
+        assert_eq!(
+            <[u8; 2]>::from_base32_str("CS-QR-", Base32::Crockford),
+            Err(Error::InvalidChar { ch: 'R', index: 4 })
+        );
+
+        // Only in the Crockford variant
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("MZXW6-YTBOI", Base32::Rfc4648NoPad),
+            Err(Error::BadLength {
+                found: 11,
+                expected: 10
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn alphabets() {
+        // `0`, `1`, `8` and `9` are not part of the RFC 4648 alphabet
+        assert_eq!(
+            <[u8; 5]>::from_base32_str("000G40R4", Base32::Rfc4648),
+            Err(Error::InvalidChar { ch: '0', index: 0 })
+        );
+        assert_eq!(
+            <[u8; 6]>::from_base32_str("CSQPYRK1E8", Base32::Rfc4648NoPad),
+            Err(Error::InvalidChar { ch: '1', index: 7 })
+        );
+    }
+
+    #[test]
+    fn strict_padding() {
+        // Missing padding
+        assert_eq!(
+            <[u8; 1]>::from_base32_str("MY", Base32::Rfc4648),
+            Err(Error::BadLength {
+                found: 2,
+                expected: 8
+            })
+        );
+        // Unexpected padding
+        assert_eq!(
+            <[u8; 1]>::from_base32_str("MY======", Base32::Crockford),
+            Err(Error::BadLength {
+                found: 8,
+                expected: 2
+            })
+        );
+        // Misplaced padding
+        assert_eq!(
+            <[u8; 1]>::from_base32_str("M=Y=====", Base32::Rfc4648),
+            Err(Error::InvalidChar { ch: '=', index: 1 })
+        );
+        assert_eq!(
+            <[u8; 1]>::from_base32_str("MY=====A", Base32::Rfc4648),
+            Err(Error::InvalidChar { ch: 'A', index: 7 })
+        );
+    }
+
+    #[test]
+    fn canonical_trailing_bits() {
+        // "MZ" decodes to "f" as well, with non-zero trailing bits
+        assert_eq!(
+            <[u8; 1]>::from_base32_str("MZ======", Base32::Rfc4648),
+            Err(Error::InvalidChar { ch: 'Z', index: 1 })
+        );
+        assert_eq!(
+            <[u8; 2]>::from_base32_str("CSQR", Base32::Crockford),
+            Err(Error::InvalidChar { ch: 'R', index: 3 })
+        );
+    }
+
+    #[test]
+    fn custom_error() {
+        let mut buf = [0u8; 8];
+        assert_eq!(Beef {}.to_base32(&mut buf, Base32::Rfc4648), "X3XQ====");
+        assert_eq!(Beef {}.to_base32(&mut buf, Base32::Crockford), "QVQG");
+
+        assert!(Beef::from_base32_str("qvqg", Base32::Crockford).is_ok());
+
+        let beef = Beef::from_base32_str("QV*G", Base32::Crockford);
+        assert!(matches!(beef, Err(BeefError::CharNotValid('*', 2))));
+
+        let beef = Beef::from_base32_str("0000", Base32::Crockford);
+        assert!(matches!(beef, Err(BeefError::InvalidBytes)));
+    }
+
+    #[test]
+    fn bigger_buffer() {
+        let mut buf = [0xaa; 64];
+        let s = 0x0102030405060708_u64.to_base32(&mut buf, Base32::Rfc4648);
+        assert_eq!(s, "BADQMBIEAMBAC===");
+        assert_eq!(
+            u64::from_base32_str(s, Base32::Rfc4648),
+            Ok(0x0102030405060708)
+        );
+    }
+}
@@ -0,0 +1,401 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "std")]
+
+mod common;
+use common::{Beef, BeefError};
+
+use dusk_bytes::{
+    DeserializableSlice, DynSerializable, Error, IoReader, IoWriter,
+    LengthPrefixed, Read, SerializableSlice, U32Size, U64Size, Varint, Write,
+};
+use std::io::{self, BufReader, Cursor, ErrorKind};
+
+/// A reader failing on every read, after returning `Interrupted` once.
+struct Broken {
+    interrupted: bool,
+}
+
+impl io::Read for Broken {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        if !self.interrupted {
+            self.interrupted = true;
+            return Err(ErrorKind::Interrupted.into());
+        }
+        Err(ErrorKind::PermissionDenied.into())
+    }
+}
+
+impl io::Write for Broken {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(ErrorKind::BrokenPipe.into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A writer taking one byte per call, and no byte once `capacity` bytes are
+/// written.
+struct Trickle {
+    bytes: Vec<u8>,
+    capacity: usize,
+}
+
+impl Trickle {
+    fn new(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::new(),
+            capacity,
+        }
+    }
+}
+
+impl Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        if self.bytes.len() == self.capacity || buf.is_empty() {
+            return Ok(0);
+        }
+        self.bytes.push(buf[0]);
+        Ok(1)
+    }
+}
+
+/// A reader giving one byte per call, and no byte once `bytes` are read.
+struct Dribble<'a> {
+    bytes: &'a [u8],
+}
+
+impl Read for Dribble<'_> {
+    fn capacity(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        match (self.bytes.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(b)) => {
+                *b = byte;
+                self.bytes = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+#[test]
+fn read() -> Result<(), Error> {
+    let bytes = [0x04, 0x03, 0x02, 0x01, 0xbe, 0xef, 0xac, 0x02];
+    let mut r = IoReader::new(BufReader::new(&bytes[..]));
+
+    assert_eq!(u32::from_reader(&mut r)?, 0x01020304);
+    assert!(Beef::from_reader(&mut r).is_ok());
+    assert_eq!(Varint::<u64>::read_from(&mut r)?, Varint(300));
+
+    Ok(())
+}
+
+#[test]
+fn write() -> Result<(), Error> {
+    let mut w = IoWriter::new(Vec::new());
+
+    0x01020304u32.write_to(&mut w)?;
+    Varint(300u64).write_to(&mut w)?;
+    String::from("dusk").write_prefixed::<Varint<usize>, _>(&mut w)?;
+
+    assert_eq!(
+        w.into_inner(),
+        [
+            0x04, 0x03, 0x02, 0x01, 0xac, 0x02, 4, b'd', b'u', b's', b'k'
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn unexpected_eof() {
+    let mut r = IoReader::new(Cursor::new([1, 2, 3]));
+    assert_eq!(
+        u64::from_reader(&mut r),
+        Err(Error::BadLength {
+            found: 3,
+            expected: 8
+        })
+    );
+    assert!(r.take_error().is_none());
+
+    let mut r = IoReader::new(Cursor::new([0xbe]));
+    assert!(matches!(
+        Beef::from_reader(&mut r),
+        Err(BeefError::UnexpectedEof)
+    ));
+}
+
+#[test]
+fn read_error() {
+    let mut r = IoReader::new(Broken { interrupted: false });
+
+    assert_eq!(r.read(&mut [0u8; 4]), Err(Error::Io));
+    assert_eq!(
+        r.take_error().map(|err| err.kind()),
+        Some(ErrorKind::PermissionDenied)
+    );
+    assert!(r.take_error().is_none());
+
+    assert_eq!(u32::from_reader(&mut r), Err(Error::Io));
+    assert_eq!(Varint::<u32>::read_from(&mut r), Err(Error::Io));
+
+    // Errors without an I/O variant report it as a bad length
+    assert!(matches!(
+        Beef::from_reader(&mut r),
+        Err(BeefError::UnexpectedEof)
+    ));
+}
+
+#[test]
+fn write_error() {
+    let mut w = IoWriter::new(Broken { interrupted: false });
+
+    assert_eq!(w.write(&[1, 2, 3]), Err(Error::Io));
+    assert_eq!(
+        w.take_error().map(|err| err.kind()),
+        Some(ErrorKind::BrokenPipe)
+    );
+    assert_eq!(0u64.write_to(&mut w), Err(Error::Io));
+
+    let mut w = IoWriter::new(Cursor::new([0u8; 2]));
+    assert_eq!(0u32.write_to(&mut w), Err(Error::Io));
+    assert_eq!(
+        w.take_error().map(|err| err.kind()),
+        Some(ErrorKind::WriteZero)
+    );
+}
+
+#[test]
+fn partial_writes() -> Result<(), Error> {
+    let mut w = Trickle::new(8);
+    0x01020304u32.to_writer(&mut w)?;
+    Beef {}.to_writer(&mut w).expect("Enough space");
+    assert_eq!(w.bytes, [0x04, 0x03, 0x02, 0x01, 0xbe, 0xef]);
+
+    // The writer stops accepting bytes half way
+    let mut w = Trickle::new(1);
+    assert_eq!(
+        0x0102u16.to_writer(&mut w),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 2
+        })
+    );
+    assert_eq!(w.bytes, [0x02]);
+
+    let mut w = Trickle::new(1);
+    assert!(matches!(
+        Beef {}.to_writer(&mut w),
+        Err(BeefError::UnexpectedEof)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn partial_dyn_writes() -> Result<(), Error> {
+    let mut w = Trickle::new(6);
+    assert_eq!(0x01020304u32.write_to(&mut w)?, 4);
+    assert_eq!(Varint(300u64).write_to(&mut w)?, 2);
+    assert_eq!(w.bytes, [0x04, 0x03, 0x02, 0x01, 0xac, 0x02]);
+
+    // A varint cut short is an error, not a shorter value
+    let mut w = Trickle::new(2);
+    assert_eq!(
+        Varint(u32::MAX).write_to(&mut w),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 5
+        })
+    );
+
+    let mut w = Trickle::new(3);
+    assert_eq!(
+        0u64.write_to(&mut w),
+        Err(Error::BadLength {
+            found: 3,
+            expected: 8
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn partial_prefixed_writes() -> Result<(), Error> {
+    let mut w = Trickle::new(11);
+    let bytes = b"abc".to_vec().into_boxed_slice();
+    assert_eq!(bytes.write_prefixed::<U32Size, _>(&mut w)?, 7);
+    assert_eq!(
+        String::from("dusk").write_prefixed::<U32Size, _>(&mut w),
+        Err(Error::BadLength {
+            found: 0,
+            expected: 4
+        })
+    );
+    assert_eq!(w.bytes, [3, 0, 0, 0, b'a', b'b', b'c', 4, 0, 0, 0]);
+
+    let mut w = Trickle::new(5);
+    assert_eq!(
+        bytes.write_prefixed::<U64Size, _>(&mut w),
+        Err(Error::BadLength {
+            found: 5,
+            expected: 8
+        })
+    );
+
+    let mut w = Trickle::new(2);
+    assert_eq!(
+        bytes.write_prefixed::<Varint<usize>, _>(&mut w),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 3
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn partial_reads() -> Result<(), Error> {
+    let bytes = [0x04, 0x03, 0x02, 0x01, 0xbe, 0xef, 0xac, 0x02];
+    let mut r = Dribble { bytes: &bytes };
+    assert_eq!(u32::from_reader(&mut r)?, 0x01020304);
+    assert_eq!(<[u8; 2]>::read_from(&mut r)?, [0xbe, 0xef]);
+    assert_eq!(Varint::<u64>::read_from(&mut r)?, Varint(300));
+
+    // A read cut short is an error, not zeroes
+    let mut r = Dribble { bytes: &[] };
+    assert_eq!(
+        Varint::<u64>::read_from(&mut r),
+        Err(Error::BadLength {
+            found: 0,
+            expected: 1
+        })
+    );
+
+    let mut r = Dribble { bytes: &[0xac] };
+    assert_eq!(
+        Varint::<u64>::read_from(&mut r),
+        Err(Error::BadLength {
+            found: 0,
+            expected: 1
+        })
+    );
+
+    let mut r = Dribble { bytes: &[1, 2] };
+    assert_eq!(
+        u32::from_reader(&mut r),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 4
+        })
+    );
+
+    let mut r = Dribble { bytes: &[0xbe] };
+    assert!(matches!(
+        Beef::from_reader(&mut r),
+        Err(BeefError::UnexpectedEof)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn partial_prefixed_reads() -> Result<(), Error> {
+    let mut r = Dribble {
+        bytes: &[
+            3, 1, 2, 3, 2, 0, 0, 0, 1, 0, 2, 0, 4, b'd', b'u', b's', b'k',
+        ],
+    };
+    assert_eq!(
+        Box::<[u8]>::read_prefixed::<Varint<usize>, _>(&mut r, 3)?,
+        Box::from([1, 2, 3])
+    );
+    assert_eq!(Vec::<u16>::read_prefixed::<U32Size, _>(&mut r, 2)?, [1, 2]);
+    assert_eq!(
+        String::read_prefixed::<Varint<usize>, _>(&mut r, 4)?,
+        "dusk"
+    );
+
+    // A read cut short is an error, not zeroes
+    let mut r = Dribble { bytes: &[3, 1] };
+    assert_eq!(
+        Box::<[u8]>::read_prefixed::<Varint<usize>, _>(&mut r, 3),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 3
+        })
+    );
+
+    let mut r = Dribble { bytes: &[4, b'd'] };
+    assert_eq!(
+        String::read_prefixed::<Varint<usize>, _>(&mut r, 4),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 4
+        })
+    );
+
+    let mut r = Dribble {
+        bytes: &[2, 1, 0, 2],
+    };
+    assert_eq!(
+        Vec::<u16>::read_prefixed::<Varint<usize>, _>(&mut r, 2),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 2
+        })
+    );
+
+    let mut r = Dribble { bytes: &[3, 0] };
+    assert_eq!(
+        Box::<[u8]>::read_prefixed::<U32Size, _>(&mut r, 3),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 4
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn read_exact_write_all() -> Result<(), Error> {
+    let mut w = Trickle::new(3);
+    assert_eq!(w.write_all(&[1, 2])?, 2);
+    assert_eq!(
+        w.write_all(&[3, 4]),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 2
+        })
+    );
+    assert_eq!(w.bytes, [1, 2, 3]);
+
+    let mut r = Dribble { bytes: &[1, 2, 3] };
+    let mut buf = [0u8; 2];
+    assert_eq!(r.read_exact(&mut buf)?, 2);
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(
+        r.read_exact(&mut buf),
+        Err(Error::BadLength {
+            found: 1,
+            expected: 2
+        })
+    );
+
+    Ok(())
+}
@@ -7,7 +7,7 @@
 mod common;
 use common::{Beef, BeefError};
 
-use dusk_bytes::ParseHexStr;
+use dusk_bytes::{Error, ParseHexStr};
 
 #[test]
 fn parse_correct_chars() -> Result<(), BeefError> {
@@ -40,6 +40,126 @@ fn parse_wrong_chars() {
     )
 }
 
+#[test]
+fn parse_exact() {
+    assert!(Beef::from_hex_str("beef00").is_ok(), "Extra chars ignored");
+    assert!(Beef::from_hex_str_exact("beef").is_ok());
+
+    let beef = Beef::from_hex_str_exact("beef00");
+    let result = matches!(beef, Err(BeefError::TrailingBytes));
+    assert!(
+        result,
+        "Expected parse failing because of the trailing chars"
+    );
+
+    assert_eq!(
+        u16::from_hex_str_exact("0201ff"),
+        Err(Error::TrailingBytes {
+            found: 6,
+            expected: 4
+        })
+    );
+    assert_eq!(
+        u16::from_hex_str_exact("02"),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 4
+        })
+    );
+}
+
+#[test]
+fn parse_exact_lengths() {
+    let cases = [
+        ("0201", Ok(0x0102)),
+        (
+            "02",
+            Err(Error::BadLength {
+                found: 2,
+                expected: 4,
+            }),
+        ),
+        (
+            "020100",
+            Err(Error::TrailingBytes {
+                found: 6,
+                expected: 4,
+            }),
+        ),
+        ("020", Err(Error::OddLength { found: 3 })),
+        ("02010", Err(Error::OddLength { found: 5 })),
+        (
+            "",
+            Err(Error::BadLength {
+                found: 0,
+                expected: 4,
+            }),
+        ),
+        ("02g1", Err(Error::InvalidChar { ch: 'g', index: 2 })),
+    ];
+
+    for (s, expected) in cases {
+        assert_eq!(u16::from_hex_str_exact(s), expected, "Parsing {s:?}");
+    }
+
+    let beef = Beef::from_hex_str_exact("bee");
+    let result = matches!(beef, Err(BeefError::OddLength(3)));
+    assert!(result, "Expected parse failing because of the odd length");
+}
+
+#[test]
+fn parse_prefixed() -> Result<(), BeefError> {
+    let beef = Beef::from_hex_str("0xbeef")?;
+    let formatted = format!("{:#x}", beef);
+    assert_eq!(formatted, "0xbeef");
+
+    assert!(
+        Beef::from_hex_str(&formatted).is_ok(),
+        "Formatting round trip"
+    );
+    assert!(Beef::from_hex_str("0XBEEF").is_ok());
+    assert!(Beef::from_hex_str_exact("0xbeef").is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn parse_prefixed_errors() {
+    assert_eq!(
+        u16::from_hex_str("0x02g1"),
+        Err(Error::InvalidChar { ch: 'g', index: 4 }),
+        "Index in the original string"
+    );
+    assert_eq!(
+        u16::from_hex_str("0x02"),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 4
+        }),
+        "Length without the prefix"
+    );
+    assert_eq!(
+        u16::from_hex_str_exact("0x020100"),
+        Err(Error::TrailingBytes {
+            found: 6,
+            expected: 4
+        })
+    );
+    assert_eq!(
+        u16::from_hex_str_exact("0x020"),
+        Err(Error::OddLength { found: 3 })
+    );
+    // Only a leading prefix is accepted
+    assert_eq!(
+        u16::from_hex_str("x0201"),
+        Err(Error::InvalidChar { ch: 'x', index: 0 })
+    );
+    assert_eq!(
+        u16::from_hex_str("0x0x0201"),
+        Err(Error::InvalidChar { ch: 'x', index: 3 })
+    );
+}
+
 mod constant {
     use dusk_bytes::hex;
 
@@ -53,4 +173,156 @@ mod constant {
         assert_eq!(BEEF_SMALL, [0xbe]);
         assert_eq!(BEEF_BIG, [0xbe, 0xef, 0x0]);
     }
+
+    #[test]
+    #[should_panic(expected = "even number of characters")]
+    fn odd_length() {
+        let _: [u8; 2] = hex(b"bee");
+    }
+
+    const KEY: [u8; 4] = dusk_bytes::hex!("deadbeef");
+
+    #[test]
+    fn literal() {
+        assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(dusk_bytes::hex!(""), [0u8; 0]);
+        assert_eq!(dusk_bytes::hex!("0xBEEF"), [0xbe, 0xef]);
+        assert_eq!(dusk_bytes::hex!("0Xbeef"), [0xbe, 0xef]);
+    }
+
+    #[test]
+    fn literal_whitespace() {
+        assert_eq!(dusk_bytes::hex!("de ad\tbe\nef"), KEY);
+        assert_eq!(dusk_bytes::hex!("0xdead 0xbeef"), KEY);
+        assert_eq!(
+            dusk_bytes::hex!(
+                "
+                de ad
+                be ef
+                "
+            ),
+            KEY
+        );
+    }
+}
+
+mod to_hex {
+    use super::*;
+    use dusk_bytes::{Serializable, ToHex};
+
+    #[test]
+    fn lower_and_upper_case() {
+        let mut hex = [0u8; 4];
+
+        Beef {}.to_hex_bytes(&mut hex);
+        assert_eq!(&hex, b"beef");
+
+        Beef {}.to_upper_hex_bytes(&mut hex);
+        assert_eq!(&hex, b"BEEF");
+    }
+
+    #[test]
+    fn same_as_formatter() {
+        let value = 0x0123456789abcdef_u64;
+
+        let mut hex = [0u8; 16];
+        value.to_hex_bytes(&mut hex);
+        let expected: String = value
+            .to_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        assert_eq!(hex, expected.as_bytes());
+
+        value.to_upper_hex_bytes(&mut hex);
+        assert_eq!(hex, expected.to_uppercase().as_bytes());
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), Error> {
+        let value = [0x00u8, 0x7f, 0x80, 0xff];
+
+        let mut hex = [0u8; 8];
+        value.to_hex_bytes(&mut hex);
+        let s = core::str::from_utf8(&hex).expect("ASCII hex");
+
+        assert_eq!(s, "007f80ff");
+        assert_eq!(<[u8; 4]>::from_hex_str_exact(s)?, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty() {
+        let mut hex = [0u8; 0];
+        [0u8; 0].to_hex_bytes(&mut hex);
+    }
+}
+
+mod constant_time {
+    use super::*;
+    use dusk_bytes::ToHex;
+
+    #[test]
+    fn same_as_fast_path() {
+        for h in 0..=u8::MAX {
+            for l in 0..=u8::MAX {
+                let bytes = [h, l];
+                let Ok(s) = core::str::from_utf8(&bytes) else {
+                    continue;
+                };
+                assert_eq!(
+                    u8::from_hex_str_ct(s),
+                    u8::from_hex_str(s),
+                    "Parsing {bytes:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn first_invalid_char() {
+        assert_eq!(
+            <[u8; 4]>::from_hex_str_ct("0xdeZdbeYf"),
+            Err(Error::InvalidChar { ch: 'Z', index: 4 })
+        );
+        assert_eq!(
+            <[u8; 2]>::from_hex_str_ct("be"),
+            Err(Error::BadLength {
+                found: 2,
+                expected: 4
+            })
+        );
+
+        let beef = Beef::from_hex_str_ct("beqf");
+        let result = matches!(beef, Err(BeefError::CharNotValid('q', 2)));
+        assert!(result, "Expected parse failing at index 2 for 'q'");
+    }
+
+    #[test]
+    fn parse() -> Result<(), Error> {
+        assert_eq!(
+            <[u8; 4]>::from_hex_str_ct("0xDEADbeef")?,
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert!(Beef::from_hex_str_ct("beef").is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn encode() {
+        let bytes: [u8; 256] = core::array::from_fn(|i| i as u8);
+
+        let mut fast = [0u8; 512];
+        let mut ct = [0u8; 512];
+
+        bytes.to_hex_bytes(&mut fast);
+        bytes.to_hex_bytes_ct(&mut ct);
+        assert_eq!(fast, ct);
+
+        bytes.to_upper_hex_bytes(&mut fast);
+        bytes.to_upper_hex_bytes_ct(&mut ct);
+        assert_eq!(fast, ct);
+    }
 }
@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "alloc")]
+
+use dusk_bytes::{
+    Error, LengthPrefix, LengthPrefixed, U32Size, U64Size, Varint,
+};
+use std::fmt::Debug;
+
+fn encode<T, P, M>(value: &T) -> Vec<u8>
+where
+    T: LengthPrefixed<M>,
+    T::Error: Debug,
+    P: LengthPrefix,
+{
+    let mut buf = vec![0u8; value.prefixed_len::<P>()];
+    let written = value.write_prefixed::<P, _>(&mut &mut buf[..]).unwrap();
+    assert_eq!(written, buf.len());
+    buf
+}
+
+fn decode<T, P, M>(bytes: &[u8], max_len: usize) -> Result<T, T::Error>
+where
+    T: LengthPrefixed<M>,
+    P: LengthPrefix,
+{
+    let mut r = bytes;
+    let value = T::read_prefixed::<P, _>(&mut r, max_len)?;
+    assert!(r.is_empty(), "Every byte read");
+    Ok(value)
+}
+
+#[test]
+fn prefixes() -> Result<(), Error> {
+    let bytes = b"abc".to_vec().into_boxed_slice();
+
+    let encoded = encode::<_, U32Size, _>(&bytes);
+    assert_eq!(encoded, [3, 0, 0, 0, b'a', b'b', b'c']);
+    assert_eq!(decode::<Box<[u8]>, U32Size, _>(&encoded, 3)?, bytes);
+
+    let encoded = encode::<_, U64Size, _>(&bytes);
+    assert_eq!(encoded, [3, 0, 0, 0, 0, 0, 0, 0, b'a', b'b', b'c']);
+    assert_eq!(decode::<Box<[u8]>, U64Size, _>(&encoded, 3)?, bytes);
+
+    let encoded = encode::<_, Varint<usize>, _>(&bytes);
+    assert_eq!(encoded, [3, b'a', b'b', b'c']);
+    assert_eq!(decode::<Box<[u8]>, Varint<usize>, _>(&encoded, 3)?, bytes);
+
+    Ok(())
+}
+
+#[test]
+fn vectors() -> Result<(), Error> {
+    let bytes = vec![1u8, 2, 3];
+    let encoded = encode::<_, Varint<usize>, _>(&bytes);
+    assert_eq!(encoded, [3, 1, 2, 3]);
+    assert_eq!(decode::<Vec<u8>, Varint<usize>, _>(&encoded, 16)?, bytes);
+
+    // Same encoding as a boxed slice, which copies the bytes at once
+    let boxed = bytes.clone().into_boxed_slice();
+    assert_eq!(encode::<_, Varint<usize>, _>(&boxed), encoded);
+    assert_eq!(decode::<Box<[u8]>, Varint<usize>, _>(&encoded, 16)?, boxed);
+
+    let numbers = vec![0x0102u16, 0x0304];
+    let encoded = encode::<_, U32Size, _>(&numbers);
+    assert_eq!(encoded, [2, 0, 0, 0, 2, 1, 4, 3]);
+    assert_eq!(decode::<Vec<u16>, U32Size, _>(&encoded, 2)?, numbers);
+
+    let varints = vec![Varint(1u64), Varint(300)];
+    let encoded = encode::<_, Varint<usize>, _>(&varints);
+    assert_eq!(encoded, [2, 1, 0xac, 0x02]);
+    assert_eq!(
+        decode::<Vec<Varint<u64>>, Varint<usize>, _>(&encoded, 2)?,
+        varints
+    );
+
+    let empty: Vec<u64> = vec![];
+    let encoded = encode::<_, Varint<usize>, _>(&empty);
+    assert_eq!(encoded, [0]);
+    assert_eq!(decode::<Vec<u64>, Varint<usize>, _>(&encoded, 0)?, empty);
+
+    Ok(())
+}
+
+#[test]
+fn strings() -> Result<(), Error> {
+    let string = String::from("dusk");
+    let encoded = encode::<_, Varint<usize>, _>(&string);
+    assert_eq!(encoded, b"\x04dusk");
+    assert_eq!(decode::<String, Varint<usize>, _>(&encoded, 4)?, string);
+
+    assert_eq!(
+        decode::<String, Varint<usize>, _>(&[2, 0xc3, 0x28], 4),
+        Err(Error::InvalidData)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn length_limit() {
+    let huge = [0xff, 0xff, 0xff, 0xff, 0x0f];
+    assert_eq!(
+        decode::<Vec<u8>, Varint<usize>, _>(&huge, 1024),
+        Err(Error::LengthLimit {
+            found: 0xffff_ffff,
+            max: 1024
+        })
+    );
+    assert_eq!(
+        decode::<String, U32Size, _>(&[5, 0, 0, 0], 4),
+        Err(Error::LengthLimit { found: 5, max: 4 })
+    );
+}
+
+#[test]
+fn truncated() {
+    assert_eq!(
+        decode::<Box<[u8]>, Varint<usize>, _>(&[3, 1, 2], 3),
+        Err(Error::BadLength {
+            found: 2,
+            expected: 3
+        })
+    );
+    assert_eq!(
+        decode::<Vec<u32>, Varint<usize>, _>(&[2, 1, 0, 0, 0], 2),
+        Err(Error::BadLength {
+            found: 0,
+            expected: 4
+        })
+    );
+}
+
+#[test]
+fn prefix_overflow() {
+    let mut buf = [0u8; 4];
+    assert_eq!(
+        U32Size::write_prefix(usize::MAX, &mut &mut buf[..]),
+        Err(Error::Overflow)
+    );
+}
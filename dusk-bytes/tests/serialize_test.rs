@@ -7,7 +7,7 @@
 mod common;
 use common::{Beef, BeefError};
 
-use dusk_bytes::{DeserializableSlice, Error, Serializable};
+use dusk_bytes::{DeserializableSlice, Error, Serializable, SerializableSlice};
 use std::fmt::Debug;
 
 #[test]
@@ -52,6 +52,39 @@ mod from_bytes {
         assert!(beef.is_ok(), "Structure created without error");
     }
 
+    #[test]
+    fn exact_buffer() {
+        let beef = Beef::from_slice_exact(&[0xbe, 0xef]);
+        assert!(beef.is_ok(), "Structure created without error");
+
+        let beef = Beef::from_slice_exact(&[0xbe, 0xef, 0x10]);
+        let result = matches!(beef, Err(BeefError::TrailingBytes));
+        assert!(result, "Trailing bytes rejected");
+
+        let beef = Beef::from_slice_exact(&[0xbe]);
+        let result = matches!(beef, Err(BeefError::UnexpectedEof));
+        assert!(result, "Not enough bytes to parse");
+    }
+
+    #[test]
+    fn exact_buffer_errors() {
+        assert_eq!(u16::from_slice_exact(&[0x02, 0x01]), Ok(0x0102));
+        assert_eq!(
+            u16::from_slice_exact(&[0x02, 0x01, 0x00]),
+            Err(Error::TrailingBytes {
+                found: 3,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            u16::from_slice_exact(&[0x02]),
+            Err(Error::BadLength {
+                found: 1,
+                expected: 2
+            })
+        );
+    }
+
     #[test]
     fn mutable_bigger_and_wrong_buffer() {
         let mut bytes = &[0xbe, 0xef, 0x10, 0x20][..];
@@ -277,6 +310,57 @@ mod to_bytes {
             ]
         );
     }
+
+    #[test]
+    fn to_slice() -> Result<(), Error> {
+        let mut buf = [0u8; 6];
+
+        0x01020304_u32.to_slice(&mut buf)?;
+        assert_eq!(buf, [0x04, 0x03, 0x02, 0x01, 0x00, 0x00]);
+
+        Beef {}.to_slice(&mut buf[4..]).expect("Exact buffer");
+        assert_eq!(buf, [0x04, 0x03, 0x02, 0x01, 0xbe, 0xef]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_slice_too_small() {
+        let mut buf = [0u8; 3];
+
+        assert_eq!(
+            0x01020304_u32.to_slice(&mut buf),
+            Err(Error::BadLength {
+                found: 3,
+                expected: 4
+            })
+        );
+        assert_eq!(buf, [0; 3], "Nothing written");
+
+        let result = Beef {}.to_slice(&mut buf[2..]);
+        assert!(matches!(result, Err(BeefError::UnexpectedEof)));
+    }
+
+    #[test]
+    fn to_writer() -> Result<(), Error> {
+        let mut buf = [0u8; 5];
+        let mut w = &mut buf[..];
+
+        0x0102_u16.to_writer(&mut w)?;
+        Beef {}.to_writer(&mut w).expect("Enough space");
+        assert_eq!(w.len(), 1);
+
+        assert_eq!(
+            0x0102_u16.to_writer(&mut w),
+            Err(Error::BadLength {
+                found: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(buf, [0x02, 0x01, 0xbe, 0xef, 0x00]);
+
+        Ok(())
+    }
 }
 
 mod functions {
@@ -376,3 +460,748 @@ mod buffers {
         Ok(())
     }
 }
+
+mod arrays {
+    use super::*;
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(<[u8; 32]>::SIZE, 32);
+        assert_eq!(<[u8; 0]>::SIZE, 0);
+        assert_eq!(<[u64; 4]>::SIZE, 32);
+        assert_eq!(<[Beef; 3]>::SIZE, 6);
+        assert_eq!(<[[u16; 2]; 3]>::SIZE, 12);
+    }
+
+    #[test]
+    fn bytes() -> Result<(), Error> {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+
+        assert_eq!(bytes.to_bytes(), bytes);
+        assert_eq!(<[u8; 4]>::from_bytes(&bytes)?, bytes);
+        assert_eq!(<[i8; 2]>::from_slice(&bytes)?, [-34, -83]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn limbs() -> Result<(), Error> {
+        let limbs = [1u64, 2, 3, 0x0102030405060708];
+        let bytes = limbs.to_bytes();
+
+        assert_eq!(bytes[..8], [1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(bytes[24..], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(<[u64; 4]>::from_bytes(&bytes)?, limbs);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> Result<(), Error> {
+        let matrix = [[1u16, 2], [3, 4], [5, 6]];
+        let bytes = matrix.to_bytes();
+
+        assert_eq!(bytes, [1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+        assert_eq!(<[[u16; 2]; 3]>::from_bytes(&bytes)?, matrix);
+
+        Ok(())
+    }
+
+    #[test]
+    fn element_error() {
+        let beefs = [Beef {}, Beef {}, Beef {}];
+        assert_eq!(beefs.to_bytes(), [0xbe, 0xef, 0xbe, 0xef, 0xbe, 0xef]);
+
+        let beefs =
+            <[Beef; 3]>::from_bytes(&[0xbe, 0xef, 0xbe, 0xee, 0xbe, 0xef]);
+        assert!(matches!(beefs, Err(BeefError::InvalidBytes)));
+    }
+
+    #[test]
+    fn from_reader() -> Result<(), Error> {
+        let mut buffer = &[1, 0, 2, 0, 3, 4][..];
+
+        assert_eq!(<[u16; 2]>::from_reader(&mut buffer)?, [1, 2]);
+        assert_eq!(<[u8; 2]>::from_reader(&mut buffer)?, [3, 4]);
+        assert!(buffer.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrapper() -> Result<(), Error> {
+        type Hashes = dusk_bytes::array!([u8; 20]; 3);
+        type Points = dusk_bytes::array!([u8; 33]; 2);
+        type Limbs = dusk_bytes::array!(u16; 40);
+
+        assert_eq!(Hashes::SIZE, 60);
+        assert_eq!(Points::SIZE, 66);
+        assert_eq!(Limbs::SIZE, 80);
+
+        let points = Points::from([[1; 33], [2; 33]]);
+        let bytes = points.to_bytes();
+        assert_eq!(bytes[..33], [1; 33]);
+        assert_eq!(bytes[33..], [2; 33]);
+        assert_eq!(Points::from_bytes(&bytes)?, points);
+
+        let limbs = Limbs::from(core::array::from_fn(|i| i as u16));
+        let bytes = limbs.to_bytes();
+        assert_eq!(bytes[78..], [39, 0]);
+        assert_eq!(Limbs::from_bytes(&bytes)?.into_inner(), limbs.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrapper_element_error() {
+        type Beefs = dusk_bytes::array!(Beef; 40);
+
+        let mut bytes =
+            Beefs::from(core::array::from_fn(|_| Beef {})).to_bytes();
+        assert!(Beefs::from_bytes(&bytes).is_ok());
+
+        bytes[79] = 0xee;
+        assert!(matches!(
+            Beefs::from_bytes(&bytes),
+            Err(BeefError::InvalidBytes)
+        ));
+    }
+}
+
+mod tuples {
+    use super::*;
+    use dusk_bytes::{Tuple2, Tuple3, tuple};
+
+    type Key = tuple!(u64, [u8; 4], u32);
+
+    #[derive(Debug, PartialEq)]
+    enum SteakError {
+        Beef,
+        #[allow(dead_code)]
+        Bytes(Error),
+    }
+
+    impl From<BeefError> for SteakError {
+        fn from(_: BeefError) -> Self {
+            Self::Beef
+        }
+    }
+
+    impl From<Error> for SteakError {
+        fn from(err: Error) -> Self {
+            Self::Bytes(err)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Weight(u16);
+
+    impl Serializable<2> for Weight {
+        type Error = SteakError;
+
+        fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+            Ok(Self(u16::from_le_bytes(*buf)))
+        }
+
+        fn to_bytes(&self) -> [u8; Self::SIZE] {
+            self.0.to_le_bytes()
+        }
+    }
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(Key::SIZE, 16);
+        assert_eq!(<Tuple2<u8, u16, 1, 2, 3>>::SIZE, 3);
+        assert_eq!(
+            <tuple!(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u64)>::SIZE,
+            19
+        );
+    }
+
+    #[test]
+    fn members() -> Result<(), Error> {
+        let key = Key::from((0x0102, [3, 4, 5, 6], 0x0708));
+        let bytes = key.to_bytes();
+
+        assert_eq!(bytes, [2, 1, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6, 8, 7, 0, 0]);
+        assert_eq!(Key::from_bytes(&bytes)?, key);
+        assert_eq!(Key::from_slice(&bytes)?.into_inner().2, 0x0708);
+
+        Ok(())
+    }
+
+    #[test]
+    fn converted_error() {
+        type Steak = Tuple3<Weight, Beef, u8, 2, 2, 1, 5>;
+
+        let steak = Steak::from((Weight(300), Beef {}, 1));
+        assert_eq!(steak.to_bytes(), [0x2c, 0x01, 0xbe, 0xef, 0x01]);
+
+        let steak = Steak::from_bytes(&[0x2c, 0x01, 0xbe, 0xee, 0x01]);
+        assert!(matches!(steak, Err(SteakError::Beef)));
+    }
+}
+
+mod endianness {
+    use super::*;
+    use dusk_bytes::{Be, Le};
+
+    #[test]
+    fn big_endian() -> Result<(), Error> {
+        assert_eq!(Be(0x01_u8).to_bytes(), [0x01]);
+        assert_eq!(Be(0x0102_u16).to_bytes(), [0x01, 0x02]);
+        assert_eq!(Be(0x01020304_u32).to_bytes(), [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(
+            Be(0x0102030405060708_u64).to_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+        assert_eq!(Be(-2_i16).to_bytes(), [0xff, 0xfe]);
+        assert_eq!(Be(1_i128).to_bytes()[15], 0x01);
+
+        assert_eq!(Be::<u32>::from_bytes(&[0, 0, 1, 2])?, Be(0x0102));
+        assert_eq!(Be::<i64>::from_slice(&[0xff; 8])?, Be(-1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn little_endian() -> Result<(), Error> {
+        assert_eq!(Le(0x0102_u16).to_bytes(), 0x0102_u16.to_bytes());
+        assert_eq!(Le(-12345678_i32).to_bytes(), [0xb2, 0x9e, 0x43, 0xff]);
+
+        assert_eq!(Le::<u32>::from_bytes(&[0, 0, 1, 2])?, Le(0x02010000));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed() -> Result<(), Error> {
+        let mut buffer = &[0x01, 0x02, 0x01, 0x02][..];
+
+        let be = Be::<u16>::from_reader(&mut buffer)?;
+        let le = Le::<u16>::from_reader(&mut buffer)?;
+
+        assert_eq!(be.0, 0x0102);
+        assert_eq!(le.0, 0x0201);
+        assert_eq!(Be::from(0x0102_u16), be);
+
+        Ok(())
+    }
+}
+
+mod strict {
+    use super::*;
+    use core::num::{NonZeroI16, NonZeroU32};
+    use dusk_bytes::{Be, CanonicalNan};
+
+    #[test]
+    fn bool() -> Result<(), Error> {
+        assert_eq!(false.to_bytes(), [0]);
+        assert_eq!(true.to_bytes(), [1]);
+
+        assert!(!bool::from_bytes(&[0])?);
+        assert!(bool::from_bytes(&[1])?);
+        assert_eq!(bool::from_bytes(&[2]), Err(Error::InvalidData));
+        assert_eq!(bool::from_bytes(&[0xff]), Err(Error::InvalidData));
+
+        assert_eq!(<[bool; 2]>::from_bytes(&[1, 0])?, [true, false]);
+        assert_eq!(<[bool; 2]>::from_bytes(&[1, 3]), Err(Error::InvalidData));
+
+        Ok(())
+    }
+
+    #[test]
+    fn char() -> Result<(), Error> {
+        assert_eq!('a'.to_bytes(), [0x61, 0, 0, 0]);
+        assert_eq!('€'.to_bytes(), [0xac, 0x20, 0, 0]);
+
+        assert_eq!(char::from_bytes(&[0xac, 0x20, 0, 0])?, '€');
+        assert_eq!(char::from_bytes(&[0xff, 0xff, 0x10, 0])?, char::MAX);
+
+        // surrogates
+        assert_eq!(
+            char::from_bytes(&[0x00, 0xd8, 0, 0]),
+            Err(Error::InvalidData)
+        );
+        assert_eq!(
+            char::from_bytes(&[0xff, 0xdf, 0, 0]),
+            Err(Error::InvalidData)
+        );
+        // past `char::MAX`
+        assert_eq!(
+            char::from_bytes(&[0x00, 0x00, 0x11, 0]),
+            Err(Error::InvalidData)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn floats() -> Result<(), Error> {
+        assert_eq!(1.5_f32.to_bytes(), [0, 0, 0xc0, 0x3f]);
+        assert_eq!(Be(1.5_f32).to_bytes(), [0x3f, 0xc0, 0, 0]);
+        assert_eq!(f64::from_bytes(&(-0.25_f64).to_bytes())?, -0.25);
+
+        // bit exact, negative zero and NaN payloads are preserved
+        let zero = f32::from_bytes(&(-0.0_f32).to_bytes())?;
+        assert_eq!(zero.to_bits(), (-0.0_f32).to_bits());
+
+        let nan = f64::from_bits(0x7ff8_0000_0000_0001);
+        let decoded = f64::from_bytes(&nan.to_bytes())?;
+        assert_eq!(decoded.to_bits(), nan.to_bits());
+
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_nan() -> Result<(), Error> {
+        let nan = f64::from_bits(0x7ff8_0000_0000_0001);
+
+        // The canonical NaNs are fixed bit patterns, whatever `NAN` is
+        let f32_nan = [0x00, 0x00, 0xc0, 0x7f];
+        let f64_nan = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x7f];
+
+        assert_eq!(CanonicalNan(nan).to_bytes(), f64_nan);
+        assert_eq!(CanonicalNan(-f64::NAN).to_bytes(), f64_nan);
+        assert_eq!(
+            CanonicalNan(f32::from_bits(0xffc0_0001)).to_bytes(),
+            f32_nan
+        );
+        assert_eq!(
+            CanonicalNan::<f64>::from_bytes(&nan.to_bytes()),
+            Err(Error::InvalidData)
+        );
+        assert_eq!(
+            CanonicalNan::<f32>::from_bytes(&[0x00, 0x00, 0xc0, 0xff]),
+            Err(Error::InvalidData)
+        );
+
+        let decoded = CanonicalNan::<f32>::from_bytes(&f32_nan)?;
+        assert_eq!(decoded.0.to_bits(), 0x7fc0_0000);
+        let decoded = CanonicalNan::<f64>::from_bytes(&f64_nan)?;
+        assert_eq!(decoded.0.to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(
+            CanonicalNan::<f32>::from_bytes(&2.0_f32.to_bytes())?,
+            CanonicalNan(2.0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_zero() -> Result<(), Error> {
+        let value = NonZeroU32::new(0x01020304).unwrap();
+        assert_eq!(value.to_bytes(), [4, 3, 2, 1]);
+        assert_eq!(NonZeroU32::from_bytes(&[4, 3, 2, 1])?, value);
+        assert_eq!(NonZeroU32::from_bytes(&[0; 4]), Err(Error::InvalidData));
+
+        let value = NonZeroI16::new(-1).unwrap();
+        assert_eq!(value.to_bytes(), [0xff, 0xff]);
+        assert_eq!(NonZeroI16::from_slice(&[0xff, 0xff])?, value);
+        assert_eq!(NonZeroI16::from_bytes(&[0; 2]), Err(Error::InvalidData));
+
+        Ok(())
+    }
+}
+
+mod sizes {
+    use super::*;
+    use dusk_bytes::{I32Size, I64Size, U32Size, U64Size};
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(U64Size::SIZE, 8);
+        assert_eq!(U32Size::SIZE, 4);
+        assert_eq!(I64Size::SIZE, 8);
+        assert_eq!(I32Size::SIZE, 4);
+    }
+
+    #[test]
+    fn fixed_width() -> Result<(), Error> {
+        let len = U64Size::from(0x0102_usize);
+        assert_eq!(len.to_bytes(), [2, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(U64Size::from_bytes(&len.to_bytes())?.get(), 0x0102);
+
+        let len = U32Size::try_from(0x0102_usize)?;
+        assert_eq!(len.to_bytes(), [2, 1, 0, 0]);
+        assert_eq!(usize::from(U32Size::from_bytes(&[2, 1, 0, 0])?), 0x0102);
+
+        let index = I64Size::from(-2_isize);
+        assert_eq!(
+            index.to_bytes(),
+            [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(I64Size::from_bytes(&index.to_bytes())?, index);
+
+        let index = I32Size::try_from(-2_isize)?;
+        assert_eq!(index.to_bytes(), [0xfe, 0xff, 0xff, 0xff]);
+        assert_eq!(I32Size::from_slice(&index.to_bytes())?.get(), -2);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn too_big_to_encode() {
+        assert_eq!(
+            U32Size::try_from(u32::MAX as usize + 1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            I32Size::try_from(i32::MIN as isize - 1),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn too_big_to_decode() {
+        assert_eq!(
+            U64Size::from_bytes(&[0, 0, 0, 0, 1, 0, 0, 0]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            I64Size::from_bytes(&[0, 0, 0, 0x80, 0, 0, 0, 0]),
+            Err(Error::Overflow)
+        );
+    }
+}
+
+mod options {
+    use super::*;
+
+    #[test]
+    fn expected_size() {
+        assert_eq!(<Option<u8>>::SIZE, 2);
+        assert_eq!(<Option<u64>>::SIZE, 9);
+        assert_eq!(<Option<[u8; 32]>>::SIZE, 33);
+        assert_eq!(<Option<Option<u16>>>::SIZE, 4);
+    }
+
+    #[test]
+    fn presence() -> Result<(), Error> {
+        assert_eq!(Some(0x0102_u16).to_bytes(), [1, 2, 1]);
+        assert_eq!(None::<u16>.to_bytes(), [0, 0, 0]);
+
+        assert_eq!(<Option<u16>>::from_bytes(&[1, 2, 1])?, Some(0x0102));
+        assert_eq!(<Option<u16>>::from_bytes(&[1, 0, 0])?, Some(0));
+        assert_eq!(<Option<u16>>::from_bytes(&[0, 0, 0])?, None);
+
+        let nested = Some(None::<u8>);
+        assert_eq!(nested.to_bytes(), [1, 0, 0]);
+        assert_eq!(<Option<Option<u8>>>::from_bytes(&[1, 0, 0])?, nested);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_presence() {
+        assert_eq!(
+            <Option<u16>>::from_bytes(&[2, 0, 0]),
+            Err(Error::InvalidData)
+        );
+        assert_eq!(
+            <Option<u16>>::from_bytes(&[0xff, 2, 1]),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn non_zero_padding() {
+        assert_eq!(
+            <Option<u16>>::from_bytes(&[0, 1, 0]),
+            Err(Error::InvalidData)
+        );
+
+        let mut bytes = [0u8; 33];
+        bytes[32] = 1;
+        assert_eq!(
+            <Option<[u8; 32]>>::from_bytes(&bytes),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn value_error() {
+        assert_eq!(
+            <Option<bool>>::from_bytes(&[1, 2]),
+            Err(Error::InvalidData)
+        );
+    }
+
+    #[test]
+    fn wrapper() -> Result<(), Error> {
+        type Signature = dusk_bytes::option!([u8; 512]);
+        type Small = dusk_bytes::option!(u16);
+
+        assert_eq!(Signature::SIZE, 513);
+        assert_eq!(Small::SIZE, 3);
+
+        let signature = Signature::from(Some([7; 512]));
+        let bytes = signature.to_bytes();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes[1..], [7; 512]);
+        assert_eq!(Signature::from_bytes(&bytes)?, signature);
+
+        let none = Signature::from(None);
+        assert_eq!(none.to_bytes(), [0; 513]);
+        assert_eq!(Signature::from_bytes(&[0; 513])?.into_inner(), None);
+
+        // Same encoding as the built-in implementation
+        assert_eq!(Small::from(Some(0x0102)).to_bytes(), [1, 2, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrapper_invalid() {
+        type Signature = dusk_bytes::option!([u8; 512]);
+
+        let mut bytes = [0u8; 513];
+        bytes[0] = 2;
+        assert_eq!(Signature::from_bytes(&bytes), Err(Error::InvalidData));
+
+        bytes[0] = 0;
+        bytes[512] = 1;
+        assert_eq!(Signature::from_bytes(&bytes), Err(Error::InvalidData));
+    }
+}
+
+mod dyn_serializable {
+    use super::*;
+    use dusk_bytes::{DynSerializable, Read, Write};
+
+    /// A byte string of up to 255 bytes, prefixed by its length.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Memo(Vec<u8>);
+
+    impl DynSerializable for Memo {
+        type Error = Error;
+
+        fn encoded_len(&self) -> usize {
+            1 + self.0.len()
+        }
+
+        fn write_to<W>(&self, w: &mut W) -> Result<usize, Self::Error>
+        where
+            W: Write,
+        {
+            let len =
+                u8::try_from(self.0.len()).map_err(|_| Error::Overflow)?;
+            Ok(len.write_to(w)? + w.write_all(&self.0)?)
+        }
+
+        fn read_from<R>(r: &mut R) -> Result<Self, Self::Error>
+        where
+            R: Read,
+        {
+            let len = u8::read_from(r)?;
+            let mut memo = vec![0u8; len as usize];
+            r.read_exact(&mut memo)?;
+            Ok(Self(memo))
+        }
+    }
+
+    fn encode<T, M>(value: &T) -> Vec<u8>
+    where
+        T: DynSerializable<M>,
+        T::Error: Debug,
+    {
+        let mut buf = vec![0u8; value.encoded_len()];
+        let written = value.write_to(&mut &mut buf[..]).unwrap();
+        assert_eq!(written, buf.len());
+        buf
+    }
+
+    #[test]
+    fn fixed_size() -> Result<(), Error> {
+        assert_eq!(0x0102u16.encoded_len(), 2);
+        assert_eq!(encode(&0x0102u16), [2, 1]);
+
+        let mut bytes = &[0xbe, 0xef, 0xff][..];
+        assert!(Beef::read_from(&mut bytes).is_ok());
+        assert_eq!(bytes, [0xff]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn variable_size() -> Result<(), Error> {
+        let memo = Memo(b"hello".to_vec());
+        assert_eq!(memo.encoded_len(), 6);
+
+        let bytes = encode(&memo);
+        assert_eq!(bytes, *b"\x05hello");
+        assert_eq!(Memo::read_from(&mut &bytes[..])?, memo);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mixed() -> Result<(), Error> {
+        let mut buf = [0u8; 9];
+        let mut w = &mut buf[..];
+        Memo(b"abc".to_vec()).write_to(&mut w)?;
+        0x01020304u32.write_to(&mut w)?;
+        Memo(vec![]).write_to(&mut w)?;
+        assert!(w.is_empty());
+
+        let mut r = &buf[..];
+        assert_eq!(Memo::read_from(&mut r)?, Memo(b"abc".to_vec()));
+        assert_eq!(u32::read_from(&mut r)?, 0x01020304);
+        assert_eq!(Memo::read_from(&mut r)?, Memo(vec![]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn short_buffer() {
+        let mut buf = [0u8; 1];
+        assert_eq!(
+            0u16.write_to(&mut &mut buf[..]),
+            Err(Error::BadLength {
+                found: 1,
+                expected: 2
+            })
+        );
+
+        assert!(matches!(
+            Beef::read_from(&mut &[0xbe][..]),
+            Err(BeefError::UnexpectedEof)
+        ));
+        assert_eq!(
+            Memo::read_from(&mut &[3, 0][..]),
+            Err(Error::BadLength {
+                found: 1,
+                expected: 3
+            })
+        );
+    }
+}
+
+mod varints {
+    use super::*;
+    use dusk_bytes::{DynSerializable, Varint};
+
+    fn encode<T>(value: T) -> Vec<u8>
+    where
+        Varint<T>: DynSerializable<Error = Error>,
+    {
+        let value = Varint(value);
+        let mut buf = vec![0u8; value.encoded_len()];
+        value.write_to(&mut &mut buf[..]).unwrap();
+        buf
+    }
+
+    fn decode<T>(bytes: &[u8]) -> Result<T, Error>
+    where
+        Varint<T>: DynSerializable<Error = Error>,
+    {
+        let mut r = bytes;
+        let value = Varint::<T>::read_from(&mut r)?;
+        assert!(r.is_empty(), "Every byte read");
+        Ok(value.0)
+    }
+
+    #[test]
+    fn unsigned() -> Result<(), Error> {
+        let cases: [(u64, &[u8]); 6] = [
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (127, &[0x7f]),
+            (128, &[0x80, 0x01]),
+            (300, &[0xac, 0x02]),
+            (
+                u64::MAX,
+                &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+            ),
+        ];
+
+        for (value, bytes) in cases {
+            assert_eq!(encode(value), bytes);
+            assert_eq!(decode::<u64>(bytes)?, value);
+        }
+
+        assert_eq!(encode(u16::MAX), [0xff, 0xff, 0x03]);
+        assert_eq!(decode::<u16>(&[0xff, 0xff, 0x03])?, u16::MAX);
+        assert_eq!(decode::<u128>(&encode(u128::MAX))?, u128::MAX);
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed() -> Result<(), Error> {
+        let cases: [(i64, &[u8]); 6] = [
+            (0, &[0x00]),
+            (-1, &[0x01]),
+            (1, &[0x02]),
+            (-64, &[0x7f]),
+            (64, &[0x80, 0x01]),
+            (
+                i64::MIN,
+                &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+            ),
+        ];
+
+        for (value, bytes) in cases {
+            assert_eq!(encode(value), bytes);
+            assert_eq!(decode::<i64>(bytes)?, value);
+        }
+
+        assert_eq!(decode::<i64>(&encode(i64::MAX))?, i64::MAX);
+        assert_eq!(decode::<i32>(&encode(i32::MIN))?, i32::MIN);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlong() {
+        assert_eq!(decode::<u64>(&[0x80, 0x00]), Err(Error::InvalidData));
+        assert_eq!(decode::<u64>(&[0x81, 0x80, 0x00]), Err(Error::InvalidData));
+        assert_eq!(decode::<i32>(&[0x82, 0x00]), Err(Error::InvalidData));
+    }
+
+    #[test]
+    fn overflow() {
+        // 2^64
+        assert_eq!(
+            decode::<u64>(&[
+                0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02
+            ]),
+            Err(Error::Overflow)
+        );
+        // Continuation past the last byte a u64 can take
+        assert_eq!(
+            decode::<u64>(&[
+                0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x81,
+                0x00
+            ]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(decode::<u16>(&[0xff, 0xff, 0x04]), Err(Error::Overflow));
+        assert_eq!(decode::<u32>(&encode(1u64 << 32)), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn truncated() {
+        assert_eq!(
+            decode::<u64>(&[0x80]),
+            Err(Error::BadLength {
+                found: 0,
+                expected: 1
+            })
+        );
+    }
+
+    #[test]
+    fn short_writer() {
+        let mut buf = [0u8; 1];
+        assert_eq!(
+            Varint(300u32).write_to(&mut &mut buf[..]),
+            Err(Error::BadLength {
+                found: 1,
+                expected: 2
+            })
+        );
+    }
+}